@@ -0,0 +1,319 @@
+/// Linearly interpolates a drag coefficient from a Mach table
+///
+/// The table holds `(mach, cd)` pairs sorted by ascending Mach number.
+/// Speeds outside the table use the nearest end value. An empty table
+/// returns NaN.
+///
+/// # Arguments
+/// * `mach` - Speed as a Mach number
+/// * `table` - `(mach, cd)` pairs sorted by Mach number
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::drag::interpolate_cd;
+/// let table = [(0.0, 0.2), (1.0, 0.4)];
+///
+/// assert_eq!(interpolate_cd(0.5, &table), 0.30000000000000004);
+/// assert_eq!(interpolate_cd(2.0, &table), 0.4);
+/// ```
+pub fn interpolate_cd(mach: f64, table: &[(f64, f64)]) -> f64 {
+    let (first, last) = match (table.first(), table.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return f64::NAN,
+    };
+
+    if mach <= first.0 {
+        return first.1;
+    }
+    if mach >= last.0 {
+        return last.1;
+    }
+
+    let upper = table.partition_point(|&(m, _)| m <= mach);
+    let (m0, cd0) = table[upper - 1];
+    let (m1, cd1) = table[upper];
+
+    cd0 + (cd1 - cd0) * (mach - m0) / (m1 - m0)
+}
+
+/// Drag coefficient from a Mach table, smoothed through the transonic region
+///
+/// Around Mach 1 the table is averaged over a window centered on `mach`.
+/// The window is `blend_width` wide at Mach 1 and shrinks linearly to
+/// nothing `blend_width` away from it, so the curve joins the raw
+/// interpolation without a jump while kinks near Mach 1 are softened.
+/// A `blend_width` of 0 gives the raw interpolation.
+///
+/// # Arguments
+/// * `mach` - Speed as a Mach number
+/// * `table` - `(mach, cd)` pairs sorted by Mach number
+/// * `blend_width` - Width of the smoothing window at Mach 1
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::drag::{interpolate_cd, smoothed_cd};
+/// let table = [(0.5, 0.2), (1.0, 0.2), (1.5, 0.7)];
+///
+/// assert_eq!(smoothed_cd(0.9, &table, 0.0), interpolate_cd(0.9, &table));
+/// assert!(smoothed_cd(1.0, &table, 0.2) > interpolate_cd(1.0, &table));
+/// ```
+pub fn smoothed_cd(mach: f64, table: &[(f64, f64)], blend_width: f64) -> f64 {
+    let half_window = if blend_width > 0.0 {
+        blend_width / 2.0 * (1.0 - (mach - 1.0).abs() / blend_width).max(0.0)
+    } else {
+        0.0
+    };
+
+    if half_window <= 0.0 {
+        return interpolate_cd(mach, table);
+    }
+
+    let from = mach - half_window;
+    let to = mach + half_window;
+    cd_integral(from, to, table) / (to - from)
+}
+
+/// Integrates the interpolated drag coefficient between two Mach numbers
+///
+/// The interpolation is linear between table points, so the trapezoid rule
+/// over every table point inside the range is exact.
+fn cd_integral(from: f64, to: f64, table: &[(f64, f64)]) -> f64 {
+    let inner = table
+        .iter()
+        .map(|&(m, _)| m)
+        .filter(|&m| m > from && m < to);
+
+    let mut total = 0.0;
+    let mut previous = from;
+    for m in inner.chain(std::iter::once(to)) {
+        total +=
+            (m - previous) * (interpolate_cd(previous, table) + interpolate_cd(m, table)) / 2.0;
+        previous = m;
+    }
+
+    total
+}
+
+/// A drag table with a precomputed cubic spline
+///
+/// Building the spline once up front avoids searching and interpolating the
+/// raw table from scratch on every step. The curve passes through every
+/// table point and is smooth between them. Speeds outside the table use the
+/// nearest end value, like [`interpolate_cd`].
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::drag::DragTable;
+/// let table = DragTable::new(&[(0.0, 0.2), (1.0, 0.4), (2.0, 0.3)]);
+///
+/// assert_eq!(table.retardation(1.0), 0.4);
+/// assert_eq!(table.retardation(3.0), 0.3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DragTable {
+    points: Vec<(f64, f64)>,
+
+    /// Second derivative of the spline at each point
+    second: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl DragTable {
+    /// Builds a natural cubic spline through the table
+    ///
+    /// The Mach numbers must be strictly increasing. Unsorted or repeated
+    /// values give zero or negative spacing between points, which the
+    /// spline can not be built over.
+    ///
+    /// # Arguments
+    /// * `points` - `(mach, cd)` pairs sorted by strictly ascending Mach number
+    ///
+    /// # Panics
+    /// Panics if the Mach numbers are not strictly increasing.
+    pub fn new(points: &[(f64, f64)]) -> DragTable {
+        assert!(
+            points.windows(2).all(|pair| pair[0].0 < pair[1].0),
+            "Drag table Mach numbers must be strictly increasing"
+        );
+
+        let n = points.len();
+        let mut second = vec![0.0; n];
+
+        if n > 2 {
+            // Thomas algorithm on the tridiagonal system, the natural end
+            // conditions keep the first and last second derivative at 0
+            let mut diagonal = vec![0.0; n];
+            let mut rhs = vec![0.0; n];
+
+            for i in 1..n - 1 {
+                let h0 = points[i].0 - points[i - 1].0;
+                let h1 = points[i + 1].0 - points[i].0;
+                let slope0 = (points[i].1 - points[i - 1].1) / h0;
+                let slope1 = (points[i + 1].1 - points[i].1) / h1;
+
+                diagonal[i] = 2.0 * (h0 + h1);
+                rhs[i] = 6.0 * (slope1 - slope0);
+
+                if i > 1 {
+                    let factor = h0 / diagonal[i - 1];
+                    diagonal[i] -= factor * h0;
+                    rhs[i] -= factor * rhs[i - 1];
+                }
+            }
+
+            for i in (1..n - 1).rev() {
+                let h1 = points[i + 1].0 - points[i].0;
+                second[i] = (rhs[i] - h1 * second[i + 1]) / diagonal[i];
+            }
+        }
+
+        DragTable {
+            points: points.to_vec(),
+            second,
+        }
+    }
+
+    /// Returns the drag coefficient at a Mach number
+    ///
+    /// An empty table returns NaN.
+    ///
+    /// # Arguments
+    /// * `mach` - Speed as a Mach number
+    pub fn retardation(&self, mach: f64) -> f64 {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return f64::NAN,
+        };
+
+        if mach <= first.0 {
+            return first.1;
+        }
+        if mach >= last.0 {
+            return last.1;
+        }
+
+        let upper = self.points.partition_point(|&(m, _)| m <= mach);
+        let (m0, cd0) = self.points[upper - 1];
+        let (m1, cd1) = self.points[upper];
+        let (s0, s1) = (self.second[upper - 1], self.second[upper]);
+
+        let h = m1 - m0;
+        let a = (m1 - mach) / h;
+        let b = (mach - m0) / h;
+
+        a * cd0 + b * cd1 + ((a.powi(3) - a) * s0 + (b.powi(3) - b) * s1) * h.powi(2) / 6.0
+    }
+}
+
+#[cfg(test)]
+mod transonic {
+    use super::{interpolate_cd, smoothed_cd};
+
+    /// A table with a sharp rise starting exactly at Mach 1
+    const TABLE: [(f64, f64); 5] = [(0.0, 0.25), (0.8, 0.2), (1.0, 0.2), (1.2, 0.6), (2.0, 0.45)];
+
+    #[test]
+    fn interpolation() {
+        assert_eq!(interpolate_cd(-1.0, &TABLE), 0.25);
+        assert_eq!(interpolate_cd(1.0, &TABLE), 0.2);
+        assert!((interpolate_cd(1.1, &TABLE) - 0.4).abs() < 1e-12);
+        assert_eq!(interpolate_cd(5.0, &TABLE), 0.45);
+        assert!(interpolate_cd(1.0, &[]).is_nan());
+    }
+
+    #[test]
+    fn zero_width_is_raw() {
+        for i in 0..=250 {
+            let mach = i as f64 * 0.01;
+            assert_eq!(smoothed_cd(mach, &TABLE, 0.0), interpolate_cd(mach, &TABLE));
+        }
+    }
+
+    #[test]
+    fn continuous_across_mach_one() {
+        let width = 0.2;
+        let step = 1e-5;
+
+        let mut previous = smoothed_cd(0.7, &TABLE, width);
+        let mut mach = 0.7;
+        while mach < 1.3 {
+            mach += step;
+            let cd = smoothed_cd(mach, &TABLE, width);
+            assert!((cd - previous).abs() < 1e-4, "Jump at Mach {mach}");
+            previous = cd;
+        }
+
+        // The raw slope jumps from 0 to 2 at Mach 1, smoothing softens that
+        let slope =
+            |m: f64| (smoothed_cd(m + step, &TABLE, width) - smoothed_cd(m, &TABLE, width)) / step;
+        let raw_jump = 2.0;
+        assert!((slope(1.0) - slope(1.0 - step)).abs() < raw_jump / 2.0);
+    }
+
+    #[test]
+    fn matches_raw_outside_window() {
+        for mach in [0.5, 0.79, 1.21, 1.8] {
+            assert_eq!(smoothed_cd(mach, &TABLE, 0.2), interpolate_cd(mach, &TABLE));
+        }
+    }
+}
+
+#[cfg(test)]
+mod table {
+    use super::{interpolate_cd, DragTable};
+
+    fn smooth_table() -> Vec<(f64, f64)> {
+        (0..=30)
+            .map(|i| {
+                let mach = i as f64 * 0.1;
+                (mach, 0.3 + 0.1 * (2.0 * mach).sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_knots() {
+        let points = smooth_table();
+        let table = DragTable::new(&points);
+
+        for &(mach, cd) in &points {
+            assert!((table.retardation(mach) - cd).abs() < 1e-12);
+            assert!((table.retardation(mach) - interpolate_cd(mach, &points)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn close_to_linear_between_knots() {
+        let points = smooth_table();
+        let table = DragTable::new(&points);
+
+        for i in 0..=300 {
+            let mach = i as f64 * 0.01;
+            let spline = table.retardation(mach);
+            assert!((spline - interpolate_cd(mach, &points)).abs() < 1e-3);
+            assert!((spline - (0.3 + 0.1 * (2.0 * mach).sin())).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn duplicate_knots() {
+        DragTable::new(&[(0.0, 0.2), (1.0, 0.3), (1.0, 0.4), (2.0, 0.3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "strictly increasing")]
+    fn unsorted_knots() {
+        DragTable::new(&[(0.0, 0.2), (2.0, 0.3), (1.0, 0.4)]);
+    }
+
+    #[test]
+    fn short_tables() {
+        assert!(DragTable::new(&[]).retardation(1.0).is_nan());
+        assert_eq!(DragTable::new(&[(1.0, 0.3)]).retardation(0.5), 0.3);
+
+        let line = DragTable::new(&[(0.0, 0.2), (2.0, 0.6)]);
+        assert!((line.retardation(0.5) - 0.3).abs() < 1e-12);
+        assert_eq!(line.retardation(4.0), 0.6);
+    }
+}
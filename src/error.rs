@@ -0,0 +1,68 @@
+use crate::types::VectorError;
+use std::error::Error;
+use std::fmt;
+
+/// Errors returned by the fallible calculations in this crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BallisticsError {
+    /// A vector operation failed, such as normalizing a zero length vector
+    Vector(VectorError),
+
+    /// The inputs do not describe a solvable firing problem
+    NoFiringSolution,
+
+    /// An iterative solver failed to reach the required accuracy
+    NonConvergence,
+
+    /// The target is further away than the projectile can reach
+    OutOfRange,
+}
+
+impl fmt::Display for BallisticsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BallisticsError::Vector(error) => write!(f, "{error}"),
+            BallisticsError::NoFiringSolution => write!(f, "no firing solution exists"),
+            BallisticsError::NonConvergence => write!(f, "solver did not converge"),
+            BallisticsError::OutOfRange => write!(f, "target is out of range"),
+        }
+    }
+}
+
+impl Error for BallisticsError {}
+
+impl From<VectorError> for BallisticsError {
+    fn from(error: VectorError) -> BallisticsError {
+        BallisticsError::Vector(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BallisticsError;
+    use crate::types::{Vec3D, VectorError};
+
+    #[test]
+    fn from_vector_error() {
+        let zero: Result<Vec3D, BallisticsError> =
+            Vec3D::zero().try_normalize().map_err(Into::into);
+        assert_eq!(
+            zero.unwrap_err(),
+            BallisticsError::Vector(VectorError::ZeroLength)
+        );
+
+        let short = Vec3D::try_from(&[1.0][..]).map_err(BallisticsError::from);
+        assert_eq!(
+            short.unwrap_err(),
+            BallisticsError::Vector(VectorError::LengthMismatch {
+                expected: 3,
+                actual: 1
+            })
+        );
+
+        assert_eq!(
+            BallisticsError::from(VectorError::ZeroLength).to_string(),
+            VectorError::ZeroLength.to_string()
+        );
+    }
+}
@@ -1,5 +1,13 @@
+pub mod drag;
+pub mod error;
+pub mod orientation;
+pub mod plot;
 pub mod projectiles;
+pub mod solvers;
+pub mod stats;
+pub mod terrain;
 pub mod types;
+pub mod units;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
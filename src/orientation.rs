@@ -0,0 +1,163 @@
+use crate::types::Vec3D;
+
+/// Orientation of a launcher given as pitch, yaw and roll
+///
+/// In the local frame of the launcher x points out of the muzzle, y to the
+/// left and z up. With all angles at 0 the local frame lines up with the
+/// world axes. The rotations are applied in roll, pitch, yaw order.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::orientation::Orientation;
+/// let launcher = Orientation {
+///     pitch: 30f64.to_radians(),
+///     yaw: 0.0,
+///     roll: 0.0,
+/// };
+///
+/// let forward = launcher.forward();
+/// assert_eq!(forward.z, 30f64.to_radians().sin());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Orientation {
+    /// Angle of the muzzle above the xy plane in radians
+    pub pitch: f64,
+
+    /// Rotation around the z axis in radians, from the x axis towards y
+    pub yaw: f64,
+
+    /// Rotation around the muzzle direction in radians, positive lowers the
+    /// right side
+    pub roll: f64,
+}
+
+#[allow(dead_code)]
+impl Orientation {
+    /// Rotates a vector from the launcher frame into the world frame
+    ///
+    /// # Arguments
+    /// * `local` - Vector in the launcher frame
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::orientation::Orientation;
+    /// use ballistics_calculator::types::Vec3D;
+    /// let launcher = Orientation {
+    ///     pitch: 0.0,
+    ///     yaw: 90f64.to_radians(),
+    ///     roll: 0.0,
+    /// };
+    ///
+    /// let v = launcher.transform(Vec3D::new(2.0, 0.0, 1.0));
+    /// assert!(v.x.abs() < 1e-12);
+    /// assert_eq!(v.y, 2.0);
+    /// assert_eq!(v.z, 1.0);
+    /// ```
+    pub fn transform(&self, local: Vec3D) -> Vec3D {
+        let (sin_roll, cos_roll) = self.roll.sin_cos();
+        let rolled = Vec3D::new(
+            local.x,
+            local.y * cos_roll - local.z * sin_roll,
+            local.y * sin_roll + local.z * cos_roll,
+        );
+
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        let pitched = Vec3D::new(
+            rolled.x * cos_pitch - rolled.z * sin_pitch,
+            rolled.y,
+            rolled.x * sin_pitch + rolled.z * cos_pitch,
+        );
+
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        Vec3D::new(
+            pitched.x * cos_yaw - pitched.y * sin_yaw,
+            pitched.x * sin_yaw + pitched.y * cos_yaw,
+            pitched.z,
+        )
+    }
+
+    /// Returns the direction the muzzle points in
+    pub fn forward(&self) -> Vec3D {
+        self.transform(Vec3D::unit_x())
+    }
+
+    /// Returns the direction of the top of the launcher
+    pub fn up(&self) -> Vec3D {
+        self.transform(Vec3D::unit_z())
+    }
+
+    /// Returns the direction of the right side of the launcher
+    pub fn right(&self) -> Vec3D {
+        self.transform(Vec3D::new(0.0, -1.0, 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Orientation;
+    use crate::types::Vec3D;
+
+    fn close(a: Vec3D, b: Vec3D) -> bool {
+        (a - b).length() < 1e-12
+    }
+
+    #[test]
+    fn zero_orientation() {
+        let o = Orientation {
+            pitch: 0.0,
+            yaw: 0.0,
+            roll: 0.0,
+        };
+
+        assert_eq!(o.forward(), Vec3D::unit_x());
+        assert_eq!(o.up(), Vec3D::unit_z());
+        assert_eq!(o.right(), Vec3D::new(0.0, -1.0, 0.0));
+    }
+
+    #[test]
+    fn yaw_rotates_forward_into_side_axis() {
+        let o = Orientation {
+            pitch: 0.0,
+            yaw: 90f64.to_radians(),
+            roll: 0.0,
+        };
+
+        assert!(close(o.forward(), Vec3D::unit_y()));
+        assert!(close(o.right(), Vec3D::unit_x()));
+        assert!(close(o.up(), Vec3D::unit_z()));
+    }
+
+    #[test]
+    fn pitch_and_roll() {
+        let o = Orientation {
+            pitch: 90f64.to_radians(),
+            yaw: 0.0,
+            roll: 0.0,
+        };
+        assert!(close(o.forward(), Vec3D::unit_z()));
+        assert!(close(o.up(), Vec3D::new(-1.0, 0.0, 0.0)));
+
+        let o = Orientation {
+            pitch: 0.0,
+            yaw: 0.0,
+            roll: 90f64.to_radians(),
+        };
+        assert!(close(o.forward(), Vec3D::unit_x()));
+        assert!(close(o.right(), Vec3D::new(0.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn frame_stays_orthonormal() {
+        let o = Orientation {
+            pitch: 0.3,
+            yaw: -1.2,
+            roll: 2.0,
+        };
+        let (f, u, r) = (o.forward(), o.up(), o.right());
+
+        assert!(f.dot(&u).abs() < 1e-12);
+        assert!(f.dot(&r).abs() < 1e-12);
+        assert!(u.dot(&r).abs() < 1e-12);
+        assert!(close(r.cross(&f), u), "Frame should be right handed");
+    }
+}
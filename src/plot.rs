@@ -0,0 +1,68 @@
+use crate::types::Vec2D;
+
+/// Maps a point in world coordinates to integer pixel coordinates
+///
+/// World y grows upwards while screen y grows downwards, so the y axis is
+/// flipped. `origin_from_bottom_left` is where the world origin should be
+/// drawn, in pixels measured from the bottom left corner of the image, while
+/// the result is measured from the top left corner as most image formats
+/// expect.
+///
+/// # Arguments
+/// * `point` - The point to map, in world units
+/// * `origin_from_bottom_left` - Pixel position of the world origin, measured
+///   from the bottom left corner
+/// * `scale` - Pixels per world unit
+/// * `height_px` - Height of the image in pixels
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::plot::to_screen;
+/// use ballistics_calculator::types::Vec2D;
+///
+/// let origin = Vec2D::new(10.0, 20.0);
+/// let pixel = to_screen(Vec2D::new(5.0, 5.0), origin, 2.0, 100.0);
+///
+/// assert_eq!(pixel, (20, 70));
+/// ```
+pub fn to_screen(
+    point: Vec2D,
+    origin_from_bottom_left: Vec2D,
+    scale: f64,
+    height_px: f64,
+) -> (i32, i32) {
+    let x = origin_from_bottom_left.x + point.x * scale;
+    let y = height_px - (origin_from_bottom_left.y + point.y * scale);
+
+    (x.round() as i32, y.round() as i32)
+}
+
+#[cfg(test)]
+mod screen {
+    use super::to_screen;
+    use crate::types::Vec2D;
+
+    #[test]
+    fn world_origin_maps_to_screen_origin() {
+        let height = 480.0;
+        let origin = Vec2D::new(40.0, 30.0);
+        let pixel = to_screen(Vec2D::zero(), origin, 3.0, height);
+
+        // The configured origin, converted from the bottom left to the top left
+        let expected = (origin.x as i32, (height - origin.y) as i32);
+        assert_eq!(pixel, expected);
+    }
+
+    #[test]
+    fn y_axis_is_flipped() {
+        let origin = Vec2D::new(0.0, 0.0);
+        let low = to_screen(Vec2D::new(1.0, 1.0), origin, 10.0, 200.0);
+        let high = to_screen(Vec2D::new(1.0, 2.0), origin, 10.0, 200.0);
+
+        assert_eq!(low.0, high.0);
+        assert!(
+            high.1 < low.1,
+            "Higher world points should be further up the screen"
+        );
+    }
+}
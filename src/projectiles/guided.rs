@@ -0,0 +1,966 @@
+use super::FlightPhase;
+use crate::terrain::{impact_point, FlatTerrain, Terrain};
+use crate::types::Vec3D;
+
+/// Standard gravity used to convert g limits into accelerations, in m/s^2
+pub const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// Most steps the flight time queries such as
+/// [`GuidedProjectile::remaining_flight_time`] simulate before giving up
+pub const MAX_FLIGHT_STEPS: usize = 10_000_000;
+
+/// Most step attempts a single adaptive Runge-Kutta-Fehlberg integration
+/// makes before covering the rest of the interval in one step
+pub const RK45_MAX_ATTEMPTS: usize = 10_000;
+
+/// Runge-Kutta-Fehlberg stage coefficients, row `i` builds stage `i + 1`
+const RKF_STAGES: [[f64; 5]; 5] = [
+    [1.0 / 4.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 32.0, 9.0 / 32.0, 0.0, 0.0, 0.0],
+    [1932.0 / 2197.0, -7200.0 / 2197.0, 7296.0 / 2197.0, 0.0, 0.0],
+    [439.0 / 216.0, -8.0, 3680.0 / 513.0, -845.0 / 4104.0, 0.0],
+    [
+        -8.0 / 27.0,
+        2.0,
+        -3544.0 / 2565.0,
+        1859.0 / 4104.0,
+        -11.0 / 40.0,
+    ],
+];
+
+/// Weights of the fourth order Runge-Kutta-Fehlberg solution
+const RKF_ORDER_4: [f64; 6] = [
+    25.0 / 216.0,
+    0.0,
+    1408.0 / 2565.0,
+    2197.0 / 4104.0,
+    -1.0 / 5.0,
+    0.0,
+];
+
+/// Weights of the fifth order Runge-Kutta-Fehlberg solution
+const RKF_ORDER_5: [f64; 6] = [
+    16.0 / 135.0,
+    0.0,
+    6656.0 / 12825.0,
+    28561.0 / 56430.0,
+    -9.0 / 50.0,
+    2.0 / 55.0,
+];
+
+/// Numerical integration scheme used by [`GuidedProjectile::step`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Integrator {
+    /// Semi-implicit Euler with one step per call or sub-step
+    Euler,
+
+    /// Adaptive Runge-Kutta-Fehlberg 4(5)
+    ///
+    /// The step size is shrunk or grown so the estimated local error in
+    /// position and velocity stays below `tolerance`. A tolerance that is
+    /// not positive and finite can not be met and falls back to
+    /// [`Integrator::Euler`].
+    Rk45 {
+        /// Largest accepted local error estimate per step
+        tolerance: f64,
+    },
+}
+
+/// A projectile steered towards a target using proportional navigation
+///
+/// Every step the line of sight rate towards the target is measured and a
+/// lateral acceleration of `navigation_gain` times that rate is applied,
+/// capped at `max_g`. Gravity acts along the negative z axis.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+/// use ballistics_calculator::types::Vec3D;
+///
+/// let mut missile = GuidedProjectile {
+///     position: Vec3D::new(0.0, 0.0, 0.0),
+///     velocity: Vec3D::new(300.0, 50.0, 20.0),
+///     target: Vec3D::new(2000.0, 0.0, 100.0),
+///     gravity: 9.81,
+///     navigation_gain: 4.0,
+///     max_g: 30.0,
+///     max_step_distance: None,
+///     integrator: Integrator::Euler,
+/// };
+///
+/// let start = missile.distance_to_target();
+/// missile.step(0.01);
+/// assert!(missile.distance_to_target() < start);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct GuidedProjectile {
+    /// Current position of the projectile
+    pub position: Vec3D,
+
+    /// Current velocity of the projectile
+    pub velocity: Vec3D,
+
+    /// Stationary point the projectile is steering towards
+    pub target: Vec3D,
+
+    /// Gravitational acceleration pulling along the negative z axis
+    pub gravity: f64,
+
+    /// Navigation constant, typically between 3 and 5
+    ///
+    /// A gain of 0 disables guidance entirely.
+    pub navigation_gain: f64,
+
+    /// Maximum lateral acceleration in multiples of standard gravity
+    pub max_g: f64,
+
+    /// Longest distance the projectile may travel in a single integration step
+    ///
+    /// When a call to [`GuidedProjectile::step`] would move the projectile
+    /// further than this, the step is split into equal sub-steps. `None`
+    /// always integrates the whole step at once.
+    pub max_step_distance: Option<f64>,
+
+    /// Numerical integration scheme used by [`GuidedProjectile::step`]
+    pub integrator: Integrator,
+}
+
+#[allow(dead_code)]
+impl GuidedProjectile {
+    /// Returns the straight line distance to the target
+    pub fn distance_to_target(&self) -> f64 {
+        (self.target - self.position).length()
+    }
+
+    /// Returns a copy of the projectile with a different velocity
+    ///
+    /// # Arguments
+    /// * `velocity` - The new velocity
+    pub fn with_velocity(&self, velocity: Vec3D) -> GuidedProjectile {
+        GuidedProjectile { velocity, ..*self }
+    }
+
+    /// Returns a copy of the projectile moving at a different speed
+    ///
+    /// The direction of travel is kept. A projectile at rest has no direction
+    /// to keep and is returned unchanged.
+    ///
+    /// # Arguments
+    /// * `speed` - The new speed
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(3.0, 0.0, 4.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 9.81,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    ///
+    /// assert_eq!(shot.with_speed(10.0).velocity, Vec3D::new(6.0, 0.0, 8.0));
+    /// ```
+    pub fn with_speed(&self, speed: f64) -> GuidedProjectile {
+        match self.velocity.try_normalize() {
+            Ok(direction) => self.with_velocity(direction * speed),
+            Err(_) => *self,
+        }
+    }
+
+    /// Points the projectile in a new direction while keeping its speed
+    ///
+    /// A zero `direction` has no direction to point in and leaves the
+    /// velocity unchanged.
+    ///
+    /// # Arguments
+    /// * `direction` - The new direction, does not need to be normalized
+    pub fn aim_at(&mut self, direction: Vec3D) {
+        if let Ok(direction) = direction.try_normalize() {
+            self.velocity = direction * self.velocity.length();
+        }
+    }
+
+    /// Returns the exact position after `t` seconds without guidance
+    ///
+    /// Uses the closed form drag-free solution from the current state, so the
+    /// result has no integration error. This matches the flight of the
+    /// projectile when `navigation_gain` is 0.
+    ///
+    /// p = p0 + v0 * t + g * t^2 / 2
+    ///
+    /// # Arguments
+    /// * `t` - Time from the current state in seconds
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(10.0, 0.0, 10.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 10.0,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    ///
+    /// assert_eq!(shot.position_at(2.0), Vec3D::new(20.0, 0.0, 0.0));
+    /// ```
+    pub fn position_at(&self, t: f64) -> Vec3D {
+        self.position + self.velocity * t + self.gravity_acceleration() * (0.5 * t.powi(2))
+    }
+
+    /// Finds where and when the projectile hits the terrain
+    ///
+    /// A copy of the projectile is stepped forward until a step crosses the
+    /// terrain surface, leaving this one untouched. The crossing point is
+    /// found with [`impact_point`] and the time is interpolated within the
+    /// last step. A projectile already below the surface hits it immediately
+    /// at its current position.
+    ///
+    /// Returns the time until impact and the impact point, or `None` if the
+    /// projectile has not landed after [`MAX_FLIGHT_STEPS`] steps.
+    ///
+    /// # Arguments
+    /// * `dt` - The time step in seconds
+    /// * `terrain` - The ground surface
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::terrain::SlopedTerrain;
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(10.0, 0.0, 10.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 10.0,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    /// let hill = SlopedTerrain {
+    ///     height: 0.0,
+    ///     slope_x: 0.5,
+    ///     slope_y: 0.0,
+    /// };
+    ///
+    /// let (time, point) = shot.impact(0.001, &hill).unwrap();
+    /// assert!((time - 1.0).abs() < 0.01);
+    /// assert!((point.z - 0.5 * point.x).abs() < 1e-9);
+    /// ```
+    pub fn impact(&self, dt: f64, terrain: &impl Terrain) -> Option<(f64, Vec3D)> {
+        if terrain.clearance(&self.position) < 0.0 {
+            return Some((0.0, self.position));
+        }
+
+        let mut copy = *self;
+        for i in 0..MAX_FLIGHT_STEPS {
+            let previous = copy.position;
+            copy.step(dt);
+
+            if let Some(hit) = impact_point(previous, copy.position, terrain) {
+                let fraction = (hit - previous).length() / (copy.position - previous).length();
+                return Some(((i as f64 + fraction) * dt, hit));
+            }
+        }
+
+        None
+    }
+
+    /// Returns how long the projectile has left before it reaches the ground
+    ///
+    /// Same as [`GuidedProjectile::impact`] over level ground at `ground_z`,
+    /// returning only the time. A projectile already below the ground has no
+    /// time left, and one that has not landed after [`MAX_FLIGHT_STEPS`]
+    /// steps returns infinity.
+    ///
+    /// # Arguments
+    /// * `dt` - The time step in seconds
+    /// * `ground_z` - Height of the ground
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(10.0, 0.0, 10.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 10.0,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    ///
+    /// assert!((shot.remaining_flight_time(0.001, 0.0) - 2.0).abs() < 0.01);
+    /// assert_eq!(shot.position, Vec3D::new(0.0, 0.0, 0.0));
+    /// ```
+    pub fn remaining_flight_time(&self, dt: f64, ground_z: f64) -> f64 {
+        self.impact(dt, &FlatTerrain { height: ground_z })
+            .map_or(f64::INFINITY, |(time, _)| time)
+    }
+
+    /// Returns how long until the projectile reaches the top of its arc
+    ///
+    /// Without guidance this is the closed form `vz / g`. With guidance a copy
+    /// is stepped forward until the vertical speed turns negative and the
+    /// crossing is interpolated within the last step. A projectile that is
+    /// not rising has no time left, and one that is still rising after
+    /// [`MAX_FLIGHT_STEPS`] steps returns infinity.
+    ///
+    /// # Arguments
+    /// * `dt` - The time step in seconds, unused without guidance
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(10.0, 0.0, 20.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 10.0,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    ///
+    /// assert_eq!(shot.time_to_apex(0.01), 2.0);
+    /// ```
+    pub fn time_to_apex(&self, dt: f64) -> f64 {
+        if self.velocity.z <= 0.0 {
+            return 0.0;
+        }
+
+        if self.navigation_gain == 0.0 {
+            return if self.gravity > 0.0 {
+                self.velocity.z / self.gravity
+            } else {
+                f64::INFINITY
+            };
+        }
+
+        let mut copy = *self;
+        for i in 0..MAX_FLIGHT_STEPS {
+            let previous = copy.velocity.z;
+            copy.step(dt);
+
+            if copy.velocity.z <= 0.0 {
+                let fraction = previous / (previous - copy.velocity.z);
+                return (i as f64 + fraction) * dt;
+            }
+        }
+
+        f64::INFINITY
+    }
+
+    /// Returns how long until the projectile has travelled a horizontal distance
+    ///
+    /// A copy of the projectile is stepped forward until its distance from
+    /// the current position in the xy plane reaches `range_m`, and the time
+    /// is interpolated within the last step. Returns `None` if the projectile
+    /// drops below `ground_z` first or does not get there within
+    /// [`MAX_FLIGHT_STEPS`] steps.
+    ///
+    /// # Arguments
+    /// * `range_m` - Horizontal distance in meters
+    /// * `dt` - The time step in seconds
+    /// * `ground_z` - Height of the ground
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::projectiles::guided::{GuidedProjectile, Integrator};
+    /// use ballistics_calculator::types::Vec3D;
+    ///
+    /// let shot = GuidedProjectile {
+    ///     position: Vec3D::new(0.0, 0.0, 0.0),
+    ///     velocity: Vec3D::new(10.0, 0.0, 10.0),
+    ///     target: Vec3D::new(0.0, 0.0, 0.0),
+    ///     gravity: 10.0,
+    ///     navigation_gain: 0.0,
+    ///     max_g: 0.0,
+    ///     max_step_distance: None,
+    ///     integrator: Integrator::Euler,
+    /// };
+    ///
+    /// let t = shot.time_to_range(5.0, 0.001, 0.0).unwrap();
+    /// assert!((t - 0.5).abs() < 1e-9);
+    /// assert_eq!(shot.time_to_range(50.0, 0.001, 0.0), None);
+    /// ```
+    pub fn time_to_range(&self, range_m: f64, dt: f64, ground_z: f64) -> Option<f64> {
+        if range_m <= 0.0 {
+            return Some(0.0);
+        }
+
+        let start = self.position;
+        let mut copy = *self;
+        for i in 0..MAX_FLIGHT_STEPS {
+            let previous = (copy.position - start).length_xy();
+            copy.step(dt);
+            let current = (copy.position - start).length_xy();
+
+            if copy.position.z < ground_z {
+                return None;
+            }
+
+            if current >= range_m {
+                let fraction = (range_m - previous) / (current - previous);
+                return Some((i as f64 + fraction) * dt);
+            }
+        }
+
+        None
+    }
+
+    /// Returns whether the projectile is rising, at its apex or descending
+    ///
+    /// Based on the z component of the velocity, which points up.
+    pub fn flight_phase(&self) -> FlightPhase {
+        FlightPhase::from_vertical_speed(self.velocity.z)
+    }
+
+    /// Returns the acceleration caused by gravity
+    pub fn gravity_acceleration(&self) -> Vec3D {
+        Vec3D::new(0.0, 0.0, -self.gravity)
+    }
+
+    /// Returns the sum of every acceleration acting on the projectile
+    ///
+    /// Each force is a separate method returning its own acceleration, so
+    /// terms can be added, removed or tested in isolation.
+    pub fn total_acceleration(&self) -> Vec3D {
+        let terms: [fn(&GuidedProjectile) -> Vec3D; 2] = [
+            GuidedProjectile::gravity_acceleration,
+            GuidedProjectile::guidance_acceleration,
+        ];
+
+        terms
+            .iter()
+            .fold(Vec3D::zero(), |total, term| total + term(self))
+    }
+
+    /// Returns the proportional navigation acceleration for the current state
+    ///
+    /// The line of sight rate is calculated as (R x Vr) / |R|^2 where R is the
+    /// line of sight and Vr is the velocity relative to the target. The
+    /// commanded acceleration is N * (rate x V), limited to `max_g`.
+    pub fn guidance_acceleration(&self) -> Vec3D {
+        let line_of_sight = self.target - self.position;
+        let range_squared = line_of_sight.dot(&line_of_sight);
+
+        if range_squared == 0.0 || self.navigation_gain == 0.0 {
+            return Vec3D::zero();
+        }
+
+        let relative_velocity = self.velocity * -1.0;
+        let rate = line_of_sight.cross(&relative_velocity) * (1.0 / range_squared);
+        let command = rate.cross(&self.velocity) * self.navigation_gain;
+
+        let limit = self.max_g * STANDARD_GRAVITY;
+        let magnitude = command.length();
+        if magnitude > limit {
+            command * (limit / magnitude)
+        } else {
+            command
+        }
+    }
+
+    /// Advances the projectile by `dt` seconds
+    ///
+    /// Applies the guidance acceleration and gravity using the configured
+    /// `integrator`. If `max_step_distance` is set the step is first
+    /// subdivided so no sub-step moves further than it.
+    ///
+    /// Returns the number of integration steps taken, which is always at
+    /// least 1. The average step size is `dt` divided by this count.
+    ///
+    /// # Arguments
+    /// * `dt` - The time step in seconds
+    pub fn step(&mut self, dt: f64) -> usize {
+        let sub_steps = match self.max_step_distance {
+            Some(max) if max > 0.0 => ((self.velocity.length() * dt) / max).ceil().max(1.0),
+            _ => 1.0,
+        };
+
+        let sub_dt = dt / sub_steps;
+        (0..sub_steps as usize)
+            .map(|_| self.integrate(sub_dt))
+            .sum()
+    }
+
+    /// Integrates over `dt` with the configured integrator
+    ///
+    /// Returns the number of steps taken.
+    fn integrate(&mut self, dt: f64) -> usize {
+        match self.integrator {
+            Integrator::Euler => {
+                self.euler(dt);
+                1
+            }
+            Integrator::Rk45 { tolerance } if tolerance > 0.0 && tolerance.is_finite() => {
+                self.rk45(dt, tolerance)
+            }
+            Integrator::Rk45 { .. } => {
+                self.euler(dt);
+                1
+            }
+        }
+    }
+
+    /// Performs a single semi-implicit Euler step without subdivision
+    fn euler(&mut self, dt: f64) {
+        let acceleration = self.total_acceleration();
+
+        self.velocity = self.velocity + acceleration * dt;
+        self.position = self.position + self.velocity * dt;
+    }
+
+    /// Covers `dt` with adaptive Runge-Kutta-Fehlberg steps
+    ///
+    /// Each attempt is accepted when the difference between the fourth and
+    /// fifth order solutions is within `tolerance`, and the next step size is
+    /// scaled from the ratio of the two. Steps shorter than a trillionth of
+    /// `dt` are always accepted, as are steps with a non-finite error since
+    /// shrinking the step can not recover from a NaN or infinite state. After
+    /// [`RK45_MAX_ATTEMPTS`] attempts the rest of `dt` is taken in one step.
+    ///
+    /// Returns the number of accepted steps.
+    fn rk45(&mut self, dt: f64, tolerance: f64) -> usize {
+        let min_step = dt * 1e-12;
+        let mut remaining = dt;
+        let mut h = dt;
+        let mut steps = 0;
+
+        for _ in 0..RK45_MAX_ATTEMPTS {
+            if remaining <= min_step {
+                return steps;
+            }
+
+            h = h.min(remaining);
+            let (position, velocity, error) = self.fehlberg_step(h);
+
+            if error <= tolerance || !error.is_finite() || h <= min_step {
+                self.position = position;
+                self.velocity = velocity;
+                remaining -= h;
+                steps += 1;
+            }
+
+            let factor = if error > 0.0 && error.is_finite() {
+                (0.9 * (tolerance / error).powf(0.2)).clamp(0.2, 5.0)
+            } else {
+                5.0
+            };
+            h = (h * factor).max(min_step);
+        }
+
+        if remaining > min_step {
+            let (position, velocity, _) = self.fehlberg_step(remaining);
+            self.position = position;
+            self.velocity = velocity;
+            steps += 1;
+        }
+
+        steps
+    }
+
+    /// Takes one Runge-Kutta-Fehlberg step of size `h` without applying it
+    ///
+    /// Returns the fifth order position and velocity along with the larger
+    /// of the position and velocity error estimates.
+    fn fehlberg_step(&self, h: f64) -> (Vec3D, Vec3D, f64) {
+        let acceleration = |position: Vec3D, velocity: Vec3D| {
+            GuidedProjectile {
+                position,
+                velocity,
+                ..*self
+            }
+            .total_acceleration()
+        };
+
+        let mut position_rates = [Vec3D::zero(); 6];
+        let mut velocity_rates = [Vec3D::zero(); 6];
+
+        for stage in 0..6 {
+            let mut position = self.position;
+            let mut velocity = self.velocity;
+
+            if stage > 0 {
+                for (i, weight) in RKF_STAGES[stage - 1].iter().enumerate().take(stage) {
+                    position = position + position_rates[i] * (weight * h);
+                    velocity = velocity + velocity_rates[i] * (weight * h);
+                }
+            }
+
+            position_rates[stage] = velocity;
+            velocity_rates[stage] = acceleration(position, velocity);
+        }
+
+        let combine = |rates: &[Vec3D; 6], weights: &[f64; 6]| {
+            rates
+                .iter()
+                .zip(weights)
+                .fold(Vec3D::zero(), |sum, (rate, weight)| {
+                    sum + *rate * (weight * h)
+                })
+        };
+
+        let position_4 = self.position + combine(&position_rates, &RKF_ORDER_4);
+        let velocity_4 = self.velocity + combine(&velocity_rates, &RKF_ORDER_4);
+        let position_5 = self.position + combine(&position_rates, &RKF_ORDER_5);
+        let velocity_5 = self.velocity + combine(&velocity_rates, &RKF_ORDER_5);
+
+        let error = (position_5 - position_4)
+            .length()
+            .max((velocity_5 - velocity_4).length());
+
+        (position_5, velocity_5, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GuidedProjectile, Integrator};
+    use crate::projectiles::FlightPhase;
+    use crate::types::Vec3D;
+
+    fn missile(navigation_gain: f64) -> GuidedProjectile {
+        GuidedProjectile {
+            position: Vec3D::new(0.0, 0.0, 0.0),
+            velocity: Vec3D::new(300.0, 50.0, 20.0),
+            target: Vec3D::new(2000.0, 0.0, 100.0),
+            gravity: 9.81,
+            navigation_gain,
+            max_g: 30.0,
+            max_step_distance: None,
+            integrator: Integrator::Euler,
+        }
+    }
+
+    #[test]
+    fn converges_on_stationary_target() {
+        let mut m = missile(4.0);
+        let mut closest = m.distance_to_target();
+
+        for _ in 0..20_000 {
+            m.step(0.001);
+            closest = closest.min(m.distance_to_target());
+        }
+
+        assert!(closest < 1.0, "Closest approach was {closest}m");
+    }
+
+    #[test]
+    fn zero_gain_is_ballistic() {
+        let mut m = missile(0.0);
+        let start = m;
+        let dt = 0.001;
+        let steps = 5000;
+
+        for _ in 0..steps {
+            m.step(dt);
+        }
+
+        let t = dt * steps as f64;
+        let expected = start.position + start.velocity * t;
+
+        assert!((m.position.x - expected.x).abs() < 1e-6);
+        assert!((m.position.y - expected.y).abs() < 1e-6);
+        // Euler integration drifts by g * t * dt / 2 from the exact parabola
+        let drop = 0.5 * start.gravity * t.powi(2);
+        assert!((m.position.z - (expected.z - drop)).abs() < start.gravity * t * dt);
+    }
+
+    #[test]
+    fn position_at_matches_stepping() {
+        let m = missile(0.0);
+
+        let dt = 1e-4;
+        let mut position = m.position;
+        let mut velocity = m.velocity;
+        let mut time = 0.0;
+
+        for check in [0.5, 1.0, 2.0, 4.0] {
+            while time < check - dt / 2.0 {
+                // Average of old and new velocity is exact for constant gravity
+                let next = velocity + m.gravity_acceleration() * dt;
+                position = position + (velocity + next) * (dt / 2.0);
+                velocity = next;
+                time += dt;
+            }
+
+            let exact = m.position_at(check);
+            assert!(
+                (exact - position).length() < 1e-6,
+                "Position differs at t={check}"
+            );
+        }
+    }
+
+    #[test]
+    fn sub_stepping_improves_accuracy() {
+        let mut coarse = missile(0.0);
+        coarse.velocity = Vec3D::new(3000.0, 0.0, 3000.0);
+        let mut fine = coarse;
+        fine.max_step_distance = Some(10.0);
+
+        let dt = 0.5;
+        assert_eq!(coarse.step(dt), 1);
+        let sub_steps = fine.step(dt);
+        assert!(sub_steps > 1, "High speed shot should be subdivided");
+
+        let exact_z = 3000.0 * dt - 0.5 * 9.81 * dt.powi(2);
+        let coarse_error = (coarse.position.z - exact_z).abs();
+        let fine_error = (fine.position.z - exact_z).abs();
+        assert!(fine_error * 10.0 < coarse_error);
+    }
+
+    #[test]
+    fn slow_shot_takes_one_sub_step() {
+        let mut m = missile(0.0);
+        m.velocity = Vec3D::new(10.0, 0.0, 10.0);
+        m.max_step_distance = Some(10.0);
+
+        assert_eq!(m.step(0.1), 1);
+    }
+
+    #[test]
+    fn flight_phase() {
+        let mut m = missile(0.0);
+        m.step(0.01);
+        assert_eq!(m.flight_phase(), FlightPhase::Rising);
+
+        // Vertical speed of 20 m/s is gone after about 2 seconds
+        for _ in 0..300 {
+            m.step(0.01);
+        }
+        assert_eq!(m.flight_phase(), FlightPhase::Descending);
+    }
+
+    #[test]
+    fn with_velocity() {
+        let m = missile(4.0);
+        let v = Vec3D::new(1.0, 2.0, 3.0);
+        let changed = m.with_velocity(v);
+
+        assert_eq!(changed.velocity, v);
+        assert_eq!(changed.position, m.position);
+        assert_eq!(m.velocity, Vec3D::new(300.0, 50.0, 20.0));
+    }
+
+    #[test]
+    fn with_speed_keeps_direction() {
+        let m = missile(4.0);
+        let faster = m.with_speed(1000.0);
+
+        assert!((faster.velocity.length() - 1000.0).abs() < 1e-9);
+        assert!(faster.velocity.same_direction(&m.velocity, 1e-9));
+
+        let resting = m.with_velocity(Vec3D::zero());
+        assert_eq!(resting.with_speed(10.0).velocity, Vec3D::zero());
+    }
+
+    #[test]
+    fn aim_at_keeps_speed() {
+        let mut m = missile(4.0);
+        let speed = m.velocity.length();
+        let direction = Vec3D::new(0.0, 1.0, 1.0);
+
+        m.aim_at(direction);
+        assert!((m.velocity.length() - speed).abs() < 1e-9);
+        assert!(m.velocity.same_direction(&direction, 1e-9));
+
+        let before = m.velocity;
+        m.aim_at(Vec3D::zero());
+        assert_eq!(m.velocity, before);
+    }
+
+    #[test]
+    fn remaining_flight_time() {
+        let m = missile(0.0);
+        let dt = 1e-4;
+        let full = 2.0 * m.velocity.z / m.gravity;
+
+        let at_launch = m.remaining_flight_time(dt, 0.0);
+        assert!((at_launch - full).abs() < 1e-3, "Flight time {at_launch}");
+        assert_eq!(m.position, Vec3D::zero(), "Original should not move");
+
+        let mut apex = m;
+        while apex.flight_phase() == FlightPhase::Rising {
+            apex.step(dt);
+        }
+        let at_apex = apex.remaining_flight_time(dt, 0.0);
+        assert!((at_apex - full / 2.0).abs() < 1e-2, "Flight time {at_apex}");
+    }
+
+    #[test]
+    fn time_to_apex_without_guidance() {
+        let m = missile(0.0);
+        assert_eq!(m.time_to_apex(0.01), m.velocity.z / m.gravity);
+
+        let mut falling = m;
+        falling.velocity.z = -1.0;
+        assert_eq!(falling.time_to_apex(0.01), 0.0);
+    }
+
+    #[test]
+    fn time_to_apex_with_guidance() {
+        let mut m = missile(4.0);
+        m.target = Vec3D::new(2000.0, 0.0, 0.0);
+        let dt = 1e-3;
+        let t = m.time_to_apex(dt);
+        assert!(t > 0.0 && t.is_finite());
+
+        let mut before = m;
+        for _ in 0..(t / dt).floor() as usize {
+            before.step(dt);
+        }
+        let mut after = before;
+        after.step(dt);
+
+        assert!(before.velocity.z > 0.0);
+        assert!(after.velocity.z <= 0.0);
+    }
+
+    #[test]
+    fn time_to_range_increases_with_range() {
+        let m = missile(0.0);
+        let mut previous = 0.0;
+
+        for range in [10.0, 50.0, 100.0, 500.0, 1000.0] {
+            let t = m.time_to_range(range, 1e-3, 0.0).expect("Within range");
+            assert!(t > previous, "Time to {range}m was {t}");
+            previous = t;
+        }
+
+        assert_eq!(m.time_to_range(1e5, 1e-3, 0.0), None);
+    }
+
+    #[test]
+    fn remaining_flight_time_below_ground() {
+        let mut m = missile(0.0);
+        m.position.z = -1.0;
+
+        assert_eq!(m.remaining_flight_time(0.01, 0.0), 0.0);
+    }
+
+    #[test]
+    fn gravity_term() {
+        let m = missile(4.0);
+        assert_eq!(m.gravity_acceleration(), Vec3D::new(0.0, 0.0, -9.81));
+    }
+
+    #[test]
+    fn guidance_term() {
+        let m = missile(4.0);
+        let guidance = m.guidance_acceleration();
+
+        assert!(guidance.length() > 0.0);
+        assert!(guidance.length() <= m.max_g * super::STANDARD_GRAVITY + 1e-9);
+        assert!(
+            guidance.dot(&m.velocity).abs() < 1e-9,
+            "Guidance should be lateral"
+        );
+
+        let unguided = missile(0.0);
+        assert_eq!(unguided.guidance_acceleration(), Vec3D::zero());
+    }
+
+    #[test]
+    fn total_is_sum_of_terms() {
+        let m = missile(4.0);
+        let expected = m.gravity_acceleration() + m.guidance_acceleration();
+
+        assert_eq!(m.total_acceleration(), expected);
+    }
+
+    #[test]
+    fn rk45_is_exact_without_guidance() {
+        let mut m = missile(0.0);
+        m.integrator = Integrator::Rk45 { tolerance: 1e-3 };
+        let exact = m.position_at(3.0);
+
+        m.step(3.0);
+        assert!((m.position - exact).length() < 1e-9);
+    }
+
+    /// Flies a guided missile for 4 seconds with the given tolerance
+    fn guided_flight(tolerance: f64) -> (Vec3D, usize) {
+        let mut m = missile(4.0);
+        m.integrator = Integrator::Rk45 { tolerance };
+
+        let steps = (0..4).map(|_| m.step(1.0)).sum();
+        (m.position, steps)
+    }
+
+    #[test]
+    fn rk45_tolerance_controls_accuracy() {
+        let (reference, _) = guided_flight(1e-12);
+        let (loose, loose_steps) = guided_flight(1e-1);
+        let (tight, tight_steps) = guided_flight(1e-7);
+
+        let loose_error = (loose - reference).length();
+        let tight_error = (tight - reference).length();
+
+        assert!(
+            tight_error < loose_error,
+            "Tight error {tight_error} should be below loose error {loose_error}"
+        );
+        assert!(
+            tight_steps > loose_steps,
+            "Tight tolerance took {tight_steps} steps, loose took {loose_steps}"
+        );
+    }
+
+    #[test]
+    fn rk45_invalid_tolerance_uses_euler() {
+        for tolerance in [-1.0, 0.0, f64::NAN, f64::INFINITY] {
+            let mut rk45 = missile(4.0);
+            rk45.integrator = Integrator::Rk45 { tolerance };
+            let mut euler = missile(4.0);
+
+            assert_eq!(rk45.step(0.01), 1, "Tolerance {tolerance}");
+            euler.step(0.01);
+            assert_eq!(rk45.position, euler.position, "Tolerance {tolerance}");
+        }
+    }
+
+    #[test]
+    fn rk45_non_finite_state_terminates() {
+        let mut m = missile(4.0);
+        m.integrator = Integrator::Rk45 { tolerance: 1e-6 };
+        m.velocity.x = f64::NAN;
+
+        let steps = m.step(0.01);
+        assert!((1..=super::RK45_MAX_ATTEMPTS + 1).contains(&steps));
+        assert!(m.position.x.is_nan());
+    }
+
+    #[test]
+    fn rk45_attempts_are_capped() {
+        let mut m = missile(4.0);
+        m.integrator = Integrator::Rk45 {
+            tolerance: f64::MIN_POSITIVE,
+        };
+
+        let steps = m.step(1.0);
+        assert!(steps <= super::RK45_MAX_ATTEMPTS + 1);
+        assert!(m.position.x.is_finite());
+    }
+}
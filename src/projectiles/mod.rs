@@ -1,8 +1,274 @@
+pub mod guided;
+
+/// Vertical speed in m/s below which a projectile is considered at its apex
+pub const APEX_SPEED_EPSILON: f64 = 1e-3;
+
+/// The part of the flight a projectile is in
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlightPhase {
+    /// Moving upwards
+    Rising,
+
+    /// At the top of the arc, vertical speed within [`APEX_SPEED_EPSILON`] of 0
+    Apex,
+
+    /// Moving downwards
+    Descending,
+}
+
+impl FlightPhase {
+    /// Classifies a vertical speed, positive being upwards
+    pub fn from_vertical_speed(speed: f64) -> FlightPhase {
+        if speed > APEX_SPEED_EPSILON {
+            FlightPhase::Rising
+        } else if speed < -APEX_SPEED_EPSILON {
+            FlightPhase::Descending
+        } else {
+            FlightPhase::Apex
+        }
+    }
+}
+
 pub mod simple {
+    use super::FlightPhase;
     use crate::types::Vec2D;
+    use crate::units::Angle;
 
     pub struct Projectile {
         pub velocity: Vec2D,
         pub gravity: f64,
+        /// Quadratic drag factor, deceleration is `drag_coefficient * |v|^2`
+        pub drag_coefficient: f64,
+    }
+
+    #[allow(dead_code)]
+    impl Projectile {
+        /// Creates a projectile launched at a speed and angle
+        ///
+        /// # Arguments
+        /// * `speed` - Launch speed
+        /// * `angle_rad` - Launch angle above the x axis in radians
+        /// * `gravity` - Gravitational acceleration pulling along negative y
+        ///
+        /// # Examples
+        /// ```rust
+        /// use ballistics_calculator::projectiles::simple::Projectile;
+        /// let p = Projectile::launch(10.0, 90f64.to_radians(), 9.81);
+        ///
+        /// assert!(p.velocity.x.abs() < 1e-12);
+        /// assert_eq!(p.velocity.y, 10.0);
+        /// ```
+        pub fn launch(speed: f64, angle_rad: f64, gravity: f64) -> Projectile {
+            Projectile {
+                velocity: Vec2D::from_angle(Angle::radians(angle_rad), speed),
+                gravity,
+                drag_coefficient: 0.0,
+            }
+        }
+
+        /// Returns the exact position of the projectile after `t` seconds
+        ///
+        /// Uses the closed form drag-free solution, so the result has no
+        /// integration error.
+        ///
+        /// x = x0 + vx * t
+        /// y = y0 + vy * t - g * t^2 / 2
+        ///
+        /// # Arguments
+        /// * `start` - Position of the projectile at `t = 0`
+        /// * `t` - Time since launch in seconds
+        ///
+        /// # Examples
+        /// ```rust
+        /// use ballistics_calculator::projectiles::simple::Projectile;
+        /// use ballistics_calculator::types::Vec2D;
+        ///
+        /// let p = Projectile {
+        ///     velocity: Vec2D::new(10.0, 10.0),
+        ///     gravity: 10.0,
+        ///     drag_coefficient: 0.0,
+        /// };
+        /// let pos = p.position_at(Vec2D::new(0.0, 0.0), 2.0);
+        ///
+        /// assert_eq!(pos.x, 20.0);
+        /// assert_eq!(pos.y, 0.0);
+        /// ```
+        pub fn position_at(&self, start: Vec2D, t: f64) -> Vec2D {
+            Vec2D {
+                x: start.x + self.velocity.x * t,
+                y: start.y + self.velocity.y * t - 0.5 * self.gravity * t.powi(2),
+            }
+        }
+
+        /// Advances the projectile by one time step
+        ///
+        /// Applies gravity along negative y and a quadratic drag deceleration
+        /// opposing the velocity. The position moves by the average of the old
+        /// and new velocity, which is exact when `drag_coefficient` is zero.
+        ///
+        /// # Arguments
+        /// * `position` - Position of the projectile, updated in place
+        /// * `dt` - Time step in seconds
+        ///
+        /// # Examples
+        /// ```rust
+        /// use ballistics_calculator::projectiles::simple::Projectile;
+        /// use ballistics_calculator::types::Vec2D;
+        ///
+        /// let mut p = Projectile {
+        ///     velocity: Vec2D::new(10.0, 0.0),
+        ///     gravity: 0.0,
+        ///     drag_coefficient: 0.01,
+        /// };
+        /// let mut position = Vec2D::zero();
+        /// p.step(&mut position, 0.1);
+        ///
+        /// assert!(p.velocity.x < 10.0);
+        /// assert!(position.x > 0.0);
+        /// ```
+        pub fn step(&mut self, position: &mut Vec2D, dt: f64) {
+            let old = self.velocity;
+            let drag = self.drag_coefficient * old.length();
+
+            self.velocity = Vec2D {
+                x: old.x - drag * old.x * dt,
+                y: old.y - (drag * old.y + self.gravity) * dt,
+            };
+
+            position.x += (old.x + self.velocity.x) / 2.0 * dt;
+            position.y += (old.y + self.velocity.y) / 2.0 * dt;
+        }
+
+        /// Returns whether the projectile is rising, at its apex or descending
+        ///
+        /// Based on the y component of the velocity, which points up.
+        ///
+        /// # Examples
+        /// ```rust
+        /// use ballistics_calculator::projectiles::simple::Projectile;
+        /// use ballistics_calculator::projectiles::FlightPhase;
+        /// use ballistics_calculator::types::Vec2D;
+        ///
+        /// let p = Projectile {
+        ///     velocity: Vec2D::new(10.0, -1.0),
+        ///     gravity: 9.81,
+        ///     drag_coefficient: 0.0,
+        /// };
+        ///
+        /// assert_eq!(p.flight_phase(), FlightPhase::Descending);
+        /// ```
+        pub fn flight_phase(&self) -> FlightPhase {
+            FlightPhase::from_vertical_speed(self.velocity.y)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Projectile;
+        use crate::projectiles::FlightPhase;
+        use crate::types::Vec2D;
+
+        #[test]
+        fn position_at_matches_stepping() {
+            let p = Projectile {
+                velocity: Vec2D::new(30.0, 40.0),
+                gravity: 9.81,
+                drag_coefficient: 0.0,
+            };
+            let start = Vec2D::new(5.0, 1.0);
+
+            let dt = 1e-4;
+            let mut position = start;
+            let mut velocity = p.velocity;
+            let mut time = 0.0;
+
+            for check in [0.5, 1.0, 2.0, 4.0] {
+                while time < check - dt / 2.0 {
+                    // Average of old and new velocity is exact for constant gravity
+                    let next_vy = velocity.y - p.gravity * dt;
+                    position.x += velocity.x * dt;
+                    position.y += (velocity.y + next_vy) / 2.0 * dt;
+                    velocity.y = next_vy;
+                    time += dt;
+                }
+
+                let exact = p.position_at(start, check);
+                assert!(
+                    (exact.x - position.x).abs() < 1e-6,
+                    "x differs at t={check}"
+                );
+                assert!(
+                    (exact.y - position.y).abs() < 1e-6,
+                    "y differs at t={check}"
+                );
+            }
+        }
+
+        #[test]
+        fn launch() {
+            let p = Projectile::launch(2f64.sqrt(), 45f64.to_radians(), 9.81);
+
+            assert!((p.velocity.x - 1.0).abs() < 1e-12);
+            assert!((p.velocity.y - 1.0).abs() < 1e-12);
+            assert_eq!(p.gravity, 9.81);
+        }
+
+        #[test]
+        fn flight_phase() {
+            let mut p = Projectile {
+                velocity: Vec2D::new(10.0, 5.0),
+                gravity: 9.81,
+                drag_coefficient: 0.0,
+            };
+            assert_eq!(p.flight_phase(), FlightPhase::Rising);
+
+            p.velocity.y = 0.0;
+            assert_eq!(p.flight_phase(), FlightPhase::Apex);
+
+            p.velocity.y = -5.0;
+            assert_eq!(p.flight_phase(), FlightPhase::Descending);
+        }
+
+        /// Steps until the projectile falls back to y = 0, returning range and apex
+        fn fly(mut p: Projectile) -> (f64, f64) {
+            let mut position = Vec2D::zero();
+            let mut apex: f64 = 0.0;
+
+            loop {
+                p.step(&mut position, 1e-3);
+                apex = apex.max(position.y);
+                if position.y < 0.0 {
+                    return (position.x, apex);
+                }
+            }
+        }
+
+        #[test]
+        fn step_without_drag_matches_parabola() {
+            let mut p = Projectile::launch(50.0, 30f64.to_radians(), 9.81);
+            let exact = Projectile::launch(50.0, 30f64.to_radians(), 9.81);
+            let mut position = Vec2D::zero();
+
+            let dt = 1e-3;
+            for i in 1..=4000 {
+                p.step(&mut position, dt);
+                let expected = exact.position_at(Vec2D::zero(), i as f64 * dt);
+                assert!((position.x - expected.x).abs() < 1e-6);
+                assert!((position.y - expected.y).abs() < 1e-6);
+            }
+        }
+
+        #[test]
+        fn step_with_drag_shortens_flight() {
+            let vacuum = Projectile::launch(100.0, 45f64.to_radians(), 9.81);
+            let mut dragged = Projectile::launch(100.0, 45f64.to_radians(), 9.81);
+            dragged.drag_coefficient = 1e-3;
+
+            let (vacuum_range, vacuum_apex) = fly(vacuum);
+            let (dragged_range, dragged_apex) = fly(dragged);
+
+            assert!(dragged_range < vacuum_range);
+            assert!(dragged_apex < vacuum_apex);
+        }
     }
 }
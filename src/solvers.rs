@@ -0,0 +1,270 @@
+use crate::error::BallisticsError;
+use crate::types::{Vec3D, VectorError, ZERO_EPSILON};
+
+/// Number of intervals the flight time window is split into when searching
+/// for the first intercept
+const INTERCEPT_SCAN_STEPS: usize = 10_000;
+
+/// Number of bisection iterations used to refine an intercept time
+const INTERCEPT_BISECTIONS: usize = 100;
+
+/// Solves for the launch direction needed to hit a moving target
+///
+/// The projectile is drag-free with gravity pulling along the negative z
+/// axis, and the target moves in a straight line at constant velocity. The
+/// earliest intercept is chosen, which corresponds to the flattest
+/// trajectory.
+///
+/// Returns the normalized aim direction.
+///
+/// # Errors
+/// * [`BallisticsError::Vector`] if the target starts at the shooter
+/// * [`BallisticsError::NoFiringSolution`] if the muzzle speed is not
+///   positive, gravity is negative or the problem has no bounded intercept
+///   time
+/// * [`BallisticsError::OutOfRange`] if the projectile can not reach the target
+///
+/// # Arguments
+/// * `shooter` - Position the projectile is launched from
+/// * `muzzle_speed` - Launch speed of the projectile
+/// * `target_pos` - Position of the target at launch
+/// * `target_vel` - Velocity of the target
+/// * `gravity` - Gravitational acceleration, must not be negative
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::solvers::intercept_solution;
+/// use ballistics_calculator::types::Vec3D;
+///
+/// let aim = intercept_solution(
+///     Vec3D::new(0.0, 0.0, 0.0),
+///     100.0,
+///     Vec3D::new(100.0, 0.0, 0.0),
+///     Vec3D::new(0.0, 10.0, 0.0),
+///     9.81,
+/// )
+/// .unwrap();
+///
+/// assert!(aim.y > 0.0, "Aim should lead the target");
+/// assert!(aim.z > 0.0, "Aim should compensate for gravity");
+/// ```
+pub fn intercept_solution(
+    shooter: Vec3D,
+    muzzle_speed: f64,
+    target_pos: Vec3D,
+    target_vel: Vec3D,
+    gravity: f64,
+) -> Result<Vec3D, BallisticsError> {
+    let offset = target_pos - shooter;
+    if offset.approx_zero(ZERO_EPSILON) {
+        return Err(VectorError::ZeroLength.into());
+    }
+    if muzzle_speed <= 0.0 || gravity.is_nan() || gravity < 0.0 {
+        return Err(BallisticsError::NoFiringSolution);
+    }
+
+    // Launch velocity needed to be at the target after t seconds
+    let required = |t: f64| -> Vec3D {
+        let drop = Vec3D::new(0.0, 0.0, 0.5 * gravity * t.powi(2));
+        (offset + target_vel * t + drop) * (1.0 / t)
+    };
+    let miss = |t: f64| required(t).length() - muzzle_speed;
+
+    let target_speed = target_vel.length();
+    let horizon = if gravity > 0.0 {
+        let speed = muzzle_speed + target_speed;
+        (speed + (speed.powi(2) + 2.0 * gravity * offset.length()).sqrt()) / gravity
+    } else if target_speed != muzzle_speed {
+        offset.length() / (muzzle_speed - target_speed).abs()
+    } else {
+        // Equal speeds cancel the quadratic term of |offset + vt * t| = vm * t,
+        // leaving |offset|^2 + 2 * (offset . vt) * t = 0
+        let closing = offset.dot(&target_vel);
+        if closing >= 0.0 {
+            return Err(BallisticsError::NoFiringSolution);
+        }
+
+        let t = -offset.length_squared() / (2.0 * closing);
+        return Ok(required(t).normalize());
+    };
+
+    // The required speed grows without bound as t approaches 0, so the scan
+    // starts from a positive miss at t = 0 to catch roots inside the first step
+    let step = horizon / INTERCEPT_SCAN_STEPS as f64;
+    let mut low = 0.0;
+    let mut low_miss: f64 = 1.0;
+
+    for i in 1..=INTERCEPT_SCAN_STEPS {
+        let high = step * i as f64;
+        let high_miss = miss(high);
+
+        if low_miss.signum() != high_miss.signum() {
+            let (mut a, mut b) = (low, high);
+            for _ in 0..INTERCEPT_BISECTIONS {
+                let mid = (a + b) / 2.0;
+                if miss(mid).signum() == low_miss.signum() {
+                    a = mid;
+                } else {
+                    b = mid;
+                }
+            }
+
+            return Ok(required((a + b) / 2.0).normalize());
+        }
+
+        low = high;
+        low_miss = high_miss;
+    }
+
+    Err(BallisticsError::OutOfRange)
+}
+
+#[cfg(test)]
+mod intercept {
+    use super::intercept_solution;
+    use crate::error::BallisticsError;
+    use crate::types::{Vec3D, VectorError};
+
+    const G: f64 = 9.81;
+
+    #[test]
+    fn stationary_target_matches_direct_solution() {
+        let speed = 300.0;
+        let (x, y) = (1000.0, 20.0);
+        let aim = intercept_solution(
+            Vec3D::new(0.0, 0.0, 0.0),
+            speed,
+            Vec3D::new(x, 0.0, y),
+            Vec3D::new(0.0, 0.0, 0.0),
+            G,
+        )
+        .expect("Target is within range");
+
+        // Low angle solution of the drag-free range equation
+        let root = (speed.powi(4) - G * (G * x.powi(2) + 2.0 * y * speed.powi(2))).sqrt();
+        let expected = ((speed.powi(2) - root) / (G * x)).atan();
+
+        assert!((aim.angle_to_horizontal() - expected).abs() < 1e-9);
+        assert!(aim.y.abs() < 1e-12);
+    }
+
+    #[test]
+    fn close_range_fast_projectile_aims_flat() {
+        for (x, speed) in [(10.0, 1000.0), (5.0, 900.0), (1.0, 300.0)] {
+            let aim = intercept_solution(
+                Vec3D::new(0.0, 0.0, 0.0),
+                speed,
+                Vec3D::new(x, 0.0, 0.0),
+                Vec3D::new(0.0, 0.0, 0.0),
+                G,
+            )
+            .expect("Target is within range");
+
+            let root = (speed.powi(4) - G.powi(2) * x.powi(2)).sqrt();
+            let expected = ((speed.powi(2) - root) / (G * x)).atan();
+
+            assert!(
+                aim.angle_to_horizontal() < 1f64.to_radians(),
+                "Lobbed at {x} m, {speed} m/s"
+            );
+            assert!((aim.angle_to_horizontal() - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn crossing_target_requires_lead() {
+        let speed = 300.0;
+        let target = Vec3D::new(1000.0, 0.0, 0.0);
+        let target_vel = Vec3D::new(0.0, 50.0, 0.0);
+        let aim = intercept_solution(Vec3D::new(0.0, 0.0, 0.0), speed, target, target_vel, G)
+            .expect("Target is within range");
+
+        assert!(aim.y > 0.0, "Aim should lead the crossing target");
+
+        let t = target.x / (aim.x * speed);
+        let projectile = aim * (speed * t) - Vec3D::new(0.0, 0.0, 0.5 * G * t.powi(2));
+        let target_at = target + target_vel * t;
+
+        assert!((projectile - target_at).length() < 1e-6);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let aim = intercept_solution(
+            Vec3D::new(0.0, 0.0, 0.0),
+            10.0,
+            Vec3D::new(1000.0, 0.0, 0.0),
+            Vec3D::new(0.0, 0.0, 0.0),
+            G,
+        );
+
+        assert_eq!(aim.unwrap_err(), BallisticsError::OutOfRange);
+    }
+
+    #[test]
+    fn target_at_shooter() {
+        let shooter = Vec3D::new(1.0, 2.0, 3.0);
+        let aim = intercept_solution(shooter, 300.0, shooter, Vec3D::zero(), G);
+
+        assert_eq!(
+            aim.unwrap_err(),
+            BallisticsError::Vector(VectorError::ZeroLength)
+        );
+    }
+
+    #[test]
+    fn no_muzzle_speed() {
+        let aim = intercept_solution(Vec3D::zero(), 0.0, Vec3D::unit_x(), Vec3D::zero(), G);
+
+        assert_eq!(aim.unwrap_err(), BallisticsError::NoFiringSolution);
+    }
+
+    #[test]
+    fn no_bounded_intercept_time() {
+        // Without gravity a target fleeing at the muzzle speed is never caught
+        let aim = intercept_solution(
+            Vec3D::zero(),
+            300.0,
+            Vec3D::new(100.0, 0.0, 0.0),
+            Vec3D::new(300.0, 0.0, 0.0),
+            0.0,
+        );
+
+        assert_eq!(aim.unwrap_err(), BallisticsError::NoFiringSolution);
+    }
+
+    #[test]
+    fn head_on_target_at_muzzle_speed() {
+        let aim = intercept_solution(
+            Vec3D::zero(),
+            300.0,
+            Vec3D::new(100.0, 0.0, 0.0),
+            Vec3D::new(-300.0, 0.0, 0.0),
+            0.0,
+        )
+        .expect("Target approaches head on");
+        assert!((aim - Vec3D::unit_x()).length() < 1e-12);
+
+        // Crossing at the muzzle speed, the meeting point is still reachable
+        let target = Vec3D::new(100.0, 0.0, 0.0);
+        let target_vel = Vec3D::new(-180.0, 240.0, 0.0);
+        let aim = intercept_solution(Vec3D::zero(), 300.0, target, target_vel, 0.0)
+            .expect("Target is closing");
+
+        let t = -target.length_squared() / (2.0 * target.dot(&target_vel));
+        assert!((aim * (300.0 * t) - (target + target_vel * t)).length() < 1e-9);
+    }
+
+    #[test]
+    fn negative_gravity() {
+        let aim = intercept_solution(
+            Vec3D::zero(),
+            300.0,
+            Vec3D::new(100.0, 0.0, 0.0),
+            Vec3D::zero(),
+            -9.81,
+        );
+
+        assert_eq!(aim.unwrap_err(), BallisticsError::NoFiringSolution);
+    }
+}
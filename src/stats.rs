@@ -0,0 +1,154 @@
+use crate::types::Vec3D;
+
+/// Summary statistics for a group of impacts
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::stats::group_stats;
+/// use ballistics_calculator::types::Vec3D;
+///
+/// let impacts = [
+///     Vec3D::new(1.0, 0.0, 0.0),
+///     Vec3D::new(-1.0, 0.0, 0.0),
+///     Vec3D::new(0.0, 1.0, 0.0),
+///     Vec3D::new(0.0, -1.0, 0.0),
+/// ];
+/// let stats = group_stats(&impacts);
+///
+/// assert_eq!(stats.mean.x, 0.0);
+/// assert_eq!(stats.mean.y, 0.0);
+/// assert_eq!(stats.extreme_spread, 2.0);
+/// assert_eq!(stats.cep_radius, 1.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct GroupStats {
+    /// Mean point of impact
+    pub mean: Vec3D,
+
+    /// Largest distance between any two impacts
+    pub extreme_spread: f64,
+
+    /// Radius around the mean point of impact containing half of the impacts
+    pub cep_radius: f64,
+
+    /// Population standard deviation along each axis
+    pub std_dev: Vec3D,
+}
+
+/// Calculates summary statistics for a group of impacts
+///
+/// The circular error probable is the median distance from the mean point of
+/// impact. Distances are measured in 3D, so impacts should lie on a common
+/// target plane for the result to match the usual 2D definition.
+///
+/// An empty slice yields a summary where every field is zero.
+///
+/// # Arguments
+/// * `impacts` - The points where each projectile hit
+pub fn group_stats(impacts: &[Vec3D]) -> GroupStats {
+    let zero = Vec3D::zero();
+
+    if impacts.is_empty() {
+        return GroupStats {
+            mean: zero,
+            extreme_spread: 0.0,
+            cep_radius: 0.0,
+            std_dev: zero,
+        };
+    }
+
+    let count = impacts.len() as f64;
+    let mean = impacts.iter().fold(zero, |sum, &p| sum + p) * (1.0 / count);
+
+    let variance = impacts.iter().fold(zero, |sum, p| {
+        let d = *p - mean;
+        sum + Vec3D::new(d.x.powi(2), d.y.powi(2), d.z.powi(2))
+    }) * (1.0 / count);
+    let std_dev = Vec3D::new(variance.x.sqrt(), variance.y.sqrt(), variance.z.sqrt());
+
+    let mut extreme_spread: f64 = 0.0;
+    for (i, a) in impacts.iter().enumerate() {
+        for b in &impacts[i + 1..] {
+            extreme_spread = extreme_spread.max((*a - *b).length());
+        }
+    }
+
+    let mut distances: Vec<f64> = impacts.iter().map(|&p| (p - mean).length()).collect();
+    distances.sort_by(f64::total_cmp);
+    let middle = distances.len() / 2;
+    let cep_radius = if distances.len().is_multiple_of(2) {
+        (distances[middle - 1] + distances[middle]) / 2.0
+    } else {
+        distances[middle]
+    };
+
+    GroupStats {
+        mean,
+        extreme_spread,
+        cep_radius,
+        std_dev,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::group_stats;
+    use crate::types::Vec3D;
+
+    /// Deterministic standard normal samples using an LCG and Box-Muller
+    fn normals(count: usize) -> Vec<(f64, f64)> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut uniform = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+        };
+
+        (0..count)
+            .map(|_| {
+                let r = (-2.0 * uniform().ln()).sqrt();
+                let theta = 2.0 * std::f64::consts::PI * uniform();
+                (r * theta.cos(), r * theta.sin())
+            })
+            .collect()
+    }
+
+    fn cloud(center: Vec3D, sigma: f64) -> Vec<Vec3D> {
+        normals(2000)
+            .into_iter()
+            .map(|(a, b)| center + Vec3D::new(0.0, a * sigma, b * sigma))
+            .collect()
+    }
+
+    #[test]
+    fn mean_near_center() {
+        let center = Vec3D::new(100.0, 5.0, -2.0);
+        let stats = group_stats(&cloud(center, 0.5));
+
+        assert!((stats.mean - center).length() < 0.05);
+        assert!((stats.std_dev.y - 0.5).abs() < 0.05);
+        assert!((stats.std_dev.z - 0.5).abs() < 0.05);
+        assert_eq!(stats.std_dev.x, 0.0);
+    }
+
+    #[test]
+    fn cep_scales_with_sigma() {
+        let center = Vec3D::new(100.0, 0.0, 0.0);
+        let narrow = group_stats(&cloud(center, 1.0));
+        let wide = group_stats(&cloud(center, 2.0));
+
+        // CEP of a circular normal distribution is about 1.1774 sigma
+        assert!((narrow.cep_radius - 1.1774).abs() < 0.1);
+        assert!((wide.cep_radius / narrow.cep_radius - 2.0).abs() < 1e-9);
+        assert!(wide.extreme_spread > narrow.extreme_spread);
+    }
+
+    #[test]
+    fn empty() {
+        let stats = group_stats(&[]);
+
+        assert_eq!(stats.cep_radius, 0.0);
+        assert_eq!(stats.extreme_spread, 0.0);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::types::Vec3D;
+
+/// A ground surface described as a height for every point on the xy plane
+pub trait Terrain {
+    /// Returns the ground height at the given horizontal position
+    fn height_at(&self, x: f64, y: f64) -> f64;
+
+    /// Returns how far above the ground a point is
+    ///
+    /// Negative values mean the point is below the surface.
+    fn clearance(&self, point: &Vec3D) -> f64 {
+        point.z - self.height_at(point.x, point.y)
+    }
+}
+
+/// Level ground at a constant height
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::terrain::{FlatTerrain, Terrain};
+/// let ground = FlatTerrain { height: 0.0 };
+///
+/// assert_eq!(ground.height_at(100.0, -20.0), 0.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct FlatTerrain {
+    /// Height of the ground
+    pub height: f64,
+}
+
+/// A plane inclined along the x and y axes
+///
+/// The height is `height + slope_x * x + slope_y * y`.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::terrain::{SlopedTerrain, Terrain};
+/// let hill = SlopedTerrain {
+///     height: 0.0,
+///     slope_x: 0.1,
+///     slope_y: 0.0,
+/// };
+///
+/// assert_eq!(hill.height_at(100.0, 0.0), 10.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct SlopedTerrain {
+    /// Height of the ground at the origin
+    pub height: f64,
+
+    /// Rise in height per unit along the x axis
+    pub slope_x: f64,
+
+    /// Rise in height per unit along the y axis
+    pub slope_y: f64,
+}
+
+impl Terrain for FlatTerrain {
+    fn height_at(&self, _x: f64, _y: f64) -> f64 {
+        self.height
+    }
+}
+
+impl Terrain for SlopedTerrain {
+    fn height_at(&self, x: f64, y: f64) -> f64 {
+        self.height + self.slope_x * x + self.slope_y * y
+    }
+}
+
+/// Finds where a straight segment passes into the terrain
+///
+/// Intended to be called with the positions before and after an integration
+/// step. Returns `None` unless `from` is on or above the ground and `to` is
+/// below it, otherwise returns the crossing point linearly interpolated
+/// between the two using the clearance at each end.
+///
+/// # Arguments
+/// * `from` - Position at the start of the step
+/// * `to` - Position at the end of the step
+/// * `terrain` - The ground to test against
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::terrain::{impact_point, FlatTerrain};
+/// use ballistics_calculator::types::Vec3D;
+///
+/// let ground = FlatTerrain { height: 0.0 };
+/// let hit = impact_point(
+///     Vec3D::new(0.0, 0.0, 1.0),
+///     Vec3D::new(2.0, 0.0, -1.0),
+///     &ground,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(hit.x, 1.0);
+/// assert_eq!(hit.z, 0.0);
+/// ```
+pub fn impact_point(from: Vec3D, to: Vec3D, terrain: &impl Terrain) -> Option<Vec3D> {
+    let before = terrain.clearance(&from);
+    let after = terrain.clearance(&to);
+
+    if before < 0.0 || after >= 0.0 {
+        return None;
+    }
+
+    let t = before / (before - after);
+    Some(from + (to - from) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{impact_point, FlatTerrain, SlopedTerrain, Terrain};
+    use crate::projectiles::guided::{GuidedProjectile, Integrator};
+    use crate::types::Vec3D;
+
+    fn shot() -> GuidedProjectile {
+        GuidedProjectile {
+            position: Vec3D::new(0.0, 0.0, 0.0),
+            velocity: Vec3D::new(50.0, 0.0, 50.0),
+            target: Vec3D::new(0.0, 0.0, 0.0),
+            gravity: 9.81,
+            navigation_gain: 0.0,
+            max_g: 0.0,
+            max_step_distance: None,
+            integrator: Integrator::Euler,
+        }
+    }
+
+    /// Flies an unguided shot until it hits the terrain
+    fn impact(terrain: &impl Terrain) -> Vec3D {
+        let (_, hit) = shot().impact(0.001, terrain).expect("Shot should land");
+        hit
+    }
+
+    #[test]
+    fn flat_terrain_matches_ground_plane() {
+        let hit = impact(&FlatTerrain { height: 0.0 });
+        let expected_range = 2.0 * 50.0 * 50.0 / 9.81;
+
+        assert!(hit.z.abs() < 1e-9);
+        assert!((hit.x - expected_range).abs() < 0.1, "Range was {}", hit.x);
+    }
+
+    #[test]
+    fn slope_changes_range() {
+        let flat = impact(&FlatTerrain { height: 0.0 });
+        let uphill = impact(&SlopedTerrain {
+            height: 0.0,
+            slope_x: 0.2,
+            slope_y: 0.0,
+        });
+        let downhill = impact(&SlopedTerrain {
+            height: 0.0,
+            slope_x: -0.2,
+            slope_y: 0.0,
+        });
+
+        assert!(uphill.x < flat.x, "Firing into a hill should shorten range");
+        assert!(
+            downhill.x > flat.x,
+            "Firing down a slope should lengthen range"
+        );
+        assert!((uphill.z - 0.2 * uphill.x).abs() < 1e-9);
+    }
+
+    #[test]
+    fn slope_changes_flight_time() {
+        let (flat, _) = shot().impact(0.001, &FlatTerrain { height: 0.0 }).unwrap();
+        let (uphill, _) = shot()
+            .impact(
+                0.001,
+                &SlopedTerrain {
+                    height: 0.0,
+                    slope_x: 0.2,
+                    slope_y: 0.0,
+                },
+            )
+            .unwrap();
+
+        assert!((flat - 2.0 * 50.0 / 9.81).abs() < 1e-2);
+        assert!(uphill < flat);
+        assert_eq!(shot().remaining_flight_time(0.001, 0.0), flat);
+    }
+
+    #[test]
+    fn no_crossing() {
+        let ground = FlatTerrain { height: 0.0 };
+
+        let above = impact_point(
+            Vec3D::new(0.0, 0.0, 2.0),
+            Vec3D::new(1.0, 0.0, 1.0),
+            &ground,
+        );
+        assert!(above.is_none());
+
+        let below = impact_point(
+            Vec3D::new(0.0, 0.0, -1.0),
+            Vec3D::new(1.0, 0.0, -2.0),
+            &ground,
+        );
+        assert!(below.is_none());
+    }
+}
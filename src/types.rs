@@ -1,7 +1,20 @@
+use crate::units::Angle;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+use std::error::Error;
+use std::f64::consts::{PI, TAU};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+
 /// A 3 dimensional Cartesian vector
 ///
 /// Represents a vector using Cartesian coordinates.
 ///
+/// The crate uses a right handed frame with z pointing up, matching
+/// East-North-Up (ENU) with x as east and y as north. Use
+/// [`Vec3D::enu_to_ned`] and [`Vec3D::ned_to_enu`] to convert to and from
+/// North-East-Down (NED).
+///
 /// # Examples
 /// ```rust
 /// use ballistics_calculator::types::Vec3D;
@@ -11,7 +24,7 @@
 /// assert_eq!(v.y, 2.0);
 /// assert_eq!(v.z, 3.0);
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(dead_code)]
 pub struct Vec3D {
     /// The vectors size in the x direction
@@ -36,7 +49,7 @@ pub struct Vec3D {
 /// assert_eq!(v.x, 1.0);
 /// assert_eq!(v.y, 2.0);
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 #[allow(dead_code)]
 pub struct Vec2D {
     /// The vectors size in the x direction
@@ -49,6 +62,7 @@ pub struct Vec2D {
 /// A 3 dimensional spherical vector
 ///
 /// Represents a vector using spherical coordinates.
+#[derive(Debug, Copy, Clone)]
 pub struct Vec3DSphere {
     /// Horizontal angle from the x axis
     pub azimuth: f64,
@@ -63,6 +77,7 @@ pub struct Vec3DSphere {
 /// A 2 dimensional spherical vector
 ///
 /// Represents a vector using spherical coordinates.
+#[derive(Debug, Copy, Clone)]
 pub struct Vec2DSphere {
     /// Horizontal angle from the x axis
     pub polar: f64,
@@ -71,13 +86,220 @@ pub struct Vec2DSphere {
     pub radius: f64,
 }
 
+/// Length below which a vector is considered to have no direction
+///
+/// Used by the direction dependent methods such as [`Vec3D::normalize`],
+/// [`Vec3D::to_sphere`] and [`Vec3D::angle_between`].
+pub const ZERO_EPSILON: f64 = 1e-12;
+
+/// Errors returned by the checked vector operations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VectorError {
+    /// The vector has no length and therefore no direction
+    ZeroLength,
+
+    /// A slice had the wrong number of components for the vector
+    LengthMismatch {
+        /// Number of components the vector needs
+        expected: usize,
+
+        /// Number of components that were given
+        actual: usize,
+    },
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::ZeroLength => write!(f, "vector has zero length"),
+            VectorError::LengthMismatch { expected, actual } => {
+                write!(f, "expected {expected} components, got {actual}")
+            }
+        }
+    }
+}
+
+impl Error for VectorError {}
+
 #[allow(dead_code)]
 impl Vec3D {
     /// Creates a new 3D vector
-    pub fn new(x: f64, y: f64, z: f64) -> Vec3D {
+    pub const fn new(x: f64, y: f64, z: f64) -> Vec3D {
         Vec3D { x, y, z }
     }
 
+    /// Creates a vector with all components set to 0
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// const ORIGIN: Vec3D = Vec3D::zero();
+    ///
+    /// assert_eq!(ORIGIN.length(), 0.0);
+    /// ```
+    pub const fn zero() -> Vec3D {
+        Vec3D::new(0.0, 0.0, 0.0)
+    }
+
+    /// Creates a unit vector along the x axis
+    pub const fn unit_x() -> Vec3D {
+        Vec3D::new(1.0, 0.0, 0.0)
+    }
+
+    /// Creates a unit vector along the y axis
+    pub const fn unit_y() -> Vec3D {
+        Vec3D::new(0.0, 1.0, 0.0)
+    }
+
+    /// Creates a unit vector along the z axis
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// const UP: Vec3D = Vec3D::unit_z();
+    ///
+    /// assert_eq!(UP.z, 1.0);
+    /// ```
+    pub const fn unit_z() -> Vec3D {
+        Vec3D::new(0.0, 0.0, 1.0)
+    }
+
+    /// Creates a new 3D vector from an azimuth and elevation
+    ///
+    /// The azimuth is measured from the x axis towards the y axis and the
+    /// elevation from the xy plane towards the z axis.
+    ///
+    /// # Arguments
+    /// * `azimuth` - Horizontal angle from the x axis
+    /// * `elevation` - Angle above the xy plane
+    /// * `magnitude` - The length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// use ballistics_calculator::units::Angle;
+    /// let v = Vec3D::from_angles(Angle::degrees(0.0), Angle::radians(0.0), 2.0);
+    ///
+    /// assert_eq!(v.x, 2.0);
+    /// assert_eq!(v.z, 0.0);
+    /// ```
+    pub fn from_angles(
+        azimuth: impl Into<Angle>,
+        elevation: impl Into<Angle>,
+        magnitude: f64,
+    ) -> Vec3D {
+        let azimuth = azimuth.into().as_radians();
+        let elevation = elevation.into().as_radians();
+
+        Vec3D {
+            x: magnitude * elevation.cos() * azimuth.cos(),
+            y: magnitude * elevation.cos() * azimuth.sin(),
+            z: magnitude * elevation.sin(),
+        }
+    }
+
+    /// Creates a new 3D vector from angles in degrees
+    ///
+    /// Same as [`Vec3D::from_angles`] with both angles given in degrees.
+    ///
+    /// # Arguments
+    /// * `azimuth_deg` - Horizontal angle from the x axis in degrees
+    /// * `elevation_deg` - Angle above the xy plane in degrees
+    /// * `magnitude` - The length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::from_angles_deg(0.0, 90.0, 2.0);
+    ///
+    /// assert!(v.x.abs() < 1e-12);
+    /// assert!(v.y.abs() < 1e-12);
+    /// assert_eq!(v.z, 2.0);
+    /// ```
+    pub fn from_angles_deg(azimuth_deg: f64, elevation_deg: f64, magnitude: f64) -> Vec3D {
+        Vec3D::from_angles(
+            Angle::degrees(azimuth_deg),
+            Angle::degrees(elevation_deg),
+            magnitude,
+        )
+    }
+
+    /// Returns the weighted average of several vectors
+    ///
+    /// Each vector is scaled by its weight and the sum is divided by the total
+    /// weight. Returns `None` when the weights add up to zero, which includes
+    /// an empty slice.
+    ///
+    /// # Arguments
+    /// * `items` - `(vector, weight)` pairs
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let avg = Vec3D::weighted_average(&[
+    ///     (Vec3D::new(0.0, 0.0, 0.0), 1.0),
+    ///     (Vec3D::new(4.0, 0.0, 0.0), 3.0),
+    /// ]);
+    ///
+    /// assert_eq!(avg, Some(Vec3D::new(3.0, 0.0, 0.0)));
+    /// assert_eq!(Vec3D::weighted_average(&[]), None);
+    /// ```
+    pub fn weighted_average(items: &[(Vec3D, f64)]) -> Option<Vec3D> {
+        let total: f64 = items.iter().map(|(_, weight)| weight).sum();
+
+        if total == 0.0 {
+            return None;
+        }
+
+        let sum = items
+            .iter()
+            .fold(Vec3D::zero(), |sum, (v, weight)| sum + *v * *weight);
+
+        Some(sum * (1.0 / total))
+    }
+
+    /// Returns a copy of the vector with the x component replaced
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 2.0, 3.0).with_x(5.0);
+    ///
+    /// assert_eq!(v.x, 5.0);
+    /// assert_eq!(v.y, 2.0);
+    /// ```
+    pub fn with_x(self, x: f64) -> Vec3D {
+        Vec3D { x, ..self }
+    }
+
+    /// Returns a copy of the vector with the y component replaced
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 2.0, 3.0).with_y(5.0);
+    ///
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, 5.0);
+    /// ```
+    pub fn with_y(self, y: f64) -> Vec3D {
+        Vec3D { y, ..self }
+    }
+
+    /// Returns a copy of the vector with the z component replaced
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 2.0, 3.0).with_z(0.0);
+    ///
+    /// assert_eq!(v.z, 0.0);
+    /// assert_eq!(v.x, 1.0);
+    /// ```
+    pub fn with_z(self, z: f64) -> Vec3D {
+        Vec3D { z, ..self }
+    }
+
     /// Returns the length of the vector
     ///
     /// The length is calculated using the Pythagorean theorem.
@@ -94,6 +316,65 @@ impl Vec3D {
         (sqrt_sum).sqrt()
     }
 
+    /// Returns the squared length of the vector
+    ///
+    /// x^2 + y^2 + z^2, cheaper than [`Vec3D::length`] when only comparing
+    /// lengths.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, 4.0, 0.0);
+    /// assert_eq!(v.length_squared(), 25.0);
+    /// ```
+    pub fn length_squared(&self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns true if the length of the vector is within `epsilon` of 1
+    ///
+    /// # Arguments
+    /// * `epsilon` - Allowed difference from a length of 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// assert!(Vec3D::new(3.0, 0.0, 4.0).normalize().is_unit(1e-12));
+    /// assert!(!Vec3D::new(2.0, 0.0, 0.0).is_unit(1e-12));
+    /// ```
+    pub fn is_unit(&self, epsilon: f64) -> bool {
+        let squared = self.length_squared();
+        (1.0 - epsilon).max(0.0).powi(2) <= squared && squared <= (1.0 + epsilon).powi(2)
+    }
+
+    /// Returns the Manhattan (L1) length of the vector
+    ///
+    /// |x| + |y| + |z|
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, -4.0, 0.0);
+    /// assert_eq!(v.manhattan_length(), 7.0);
+    /// ```
+    pub fn manhattan_length(&self) -> f64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    /// Returns the Chebyshev (L-infinity) length of the vector
+    ///
+    /// max(|x|, |y|, |z|)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, -4.0, 0.0);
+    /// assert_eq!(v.chebyshev_length(), 4.0);
+    /// ```
+    pub fn chebyshev_length(&self) -> f64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
     /// Returns the length on the xy plane only
     ///
     /// The length is calculated using the Pythagorean theorem.
@@ -113,7 +394,9 @@ impl Vec3D {
     /// Updates the vector to a new lengthe
     ///
     /// Recalculates the vector to have the given length while maintaining the
-    /// ratio of the components.
+    /// ratio of the components. A length can not be negative, so the absolute
+    /// value of `new` is used and the direction never flips. A vector shorter
+    /// than [`ZERO_EPSILON`] has no direction to keep and is left unchanged.
     ///
     /// # Arguments
     /// * `new` - The new length of the vector
@@ -127,132 +410,3097 @@ impl Vec3D {
     /// assert_eq!(v.length().round(), 15.0);
     /// ```
     pub fn update_length(&mut self, new: f64) {
-        let mut sphere = self.to_sphere();
-        sphere.radius = new;
-        let cartesian = sphere.to_vec();
+        let length = self.length();
+
+        if length < ZERO_EPSILON {
+            return;
+        }
 
-        self.x = cartesian.x;
-        self.y = cartesian.y;
-        self.z = cartesian.z;
+        *self = *self * (new.abs() / length);
     }
 
-    /// Converts the vector into its 2D projection
+    /// Returns the componentwise (Hadamard) product of two vectors
     ///
-    /// The x cordinate is the square root of the sum
-    /// of the squares of the x and y axis.
+    /// Each component is multiplied with the matching component of `other`.
+    /// This is neither the [`dot`](Vec3D::dot) nor the
+    /// [`cross`](Vec3D::cross) product.
     ///
-    /// The y cordinate is the z axis.
+    /// # Arguments
+    /// * `other` - The vector to multiply with
     ///
     /// # Examples
     /// ```rust
     /// use ballistics_calculator::types::Vec3D;
-    /// let v = Vec3D::new(3.0, 4.0, 3.0);
-    /// let v2d = v.to_2d();
+    /// let v = Vec3D::new(1.0, 2.0, 3.0).hadamard(&Vec3D::new(4.0, 5.0, 6.0));
     ///
-    /// assert_eq!(v2d.x, 5.0);
-    /// assert_eq!(v2d.y, 3.0);
+    /// assert_eq!(v.x, 4.0);
+    /// assert_eq!(v.y, 10.0);
+    /// assert_eq!(v.z, 18.0);
     /// ```
-    pub fn to_2d(&self) -> Vec2D {
-        Vec2D {
-            x: self.length_xy(),
-            y: self.z,
+    pub fn hadamard(&self, other: &Vec3D) -> Vec3D {
+        Vec3D {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
         }
     }
 
-    /// Converts the Cartesian vector to spherical coordinates
+    /// Reflects the vector about a surface normal
+    ///
+    /// v - 2 * (v . n) * n
+    ///
+    /// # Arguments
+    /// * `normal` - The surface normal, must be a unit vector
     ///
     /// # Examples
     /// ```rust
     /// use ballistics_calculator::types::Vec3D;
-    /// let v = Vec3D::new(1.0, 1.0, 1.0);
-    /// let v_sphere = v.to_sphere();
+    /// let v = Vec3D::new(1.0, 0.0, -1.0);
+    /// let r = v.reflect(&Vec3D::unit_z());
     ///
-    /// assert_eq!(v_sphere.radius, (1f64 + 1f64 + 1f64).sqrt());
-    /// assert_eq!(v_sphere.azimuth, 45f64.to_radians());
+    /// assert_eq!(r.x, 1.0);
+    /// assert_eq!(r.z, 1.0);
     /// ```
-    pub fn to_sphere(&self) -> Vec3DSphere {
-        Vec3DSphere {
-            radius: self.length(),
-            azimuth: (self.y / self.x).atan(),
-            polar: (self.z / self.length_xy()).atan(),
-        }
+    pub fn reflect(&self, normal: &Vec3D) -> Vec3D {
+        *self - *normal * (2.0 * self.dot(normal))
     }
-}
 
-impl Vec3DSphere {
-    /// Converts the spherical vector to a Cartesian vector
+    /// Reflects the vector about a surface normal of any length
+    ///
+    /// Same as [`Vec3D::reflect`] but normalizes the normal first. A normal
+    /// shorter than [`ZERO_EPSILON`] describes no surface and the vector is
+    /// returned unchanged.
+    ///
+    /// # Arguments
+    /// * `normal` - The surface normal, does not need to be normalized
     ///
     /// # Examples
     /// ```rust
-    /// use ballistics_calculator::types::Vec3DSphere;
-    /// let v_sphere = Vec3DSphere {
-    ///     radius: 5.0,
-    ///     azimuth: 45f64.to_radians(),
-    ///     polar: 45f64.to_radians(),
-    /// };
-    ///
-    /// let v = v_sphere.to_vec().to_sphere();
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 0.0, -1.0);
+    /// let r = v.reflect_safe(&Vec3D::new(0.0, 0.0, 10.0));
     ///
-    /// assert_eq!(v.radius, 5.0);
-    /// assert_eq!(v.azimuth.to_degrees().round(), 45.);
-    /// assert_eq!(v.polar.to_degrees().round(), 45.);
+    /// assert_eq!(r, Vec3D::new(1.0, 0.0, 1.0));
     /// ```
-    pub fn to_vec(&self) -> Vec3D {
-        Vec3D {
-            x: self.radius * self.azimuth.cos() * self.polar.sin(),
-            y: self.radius * self.azimuth.sin() * self.polar.sin(),
-            z: self.radius * self.polar.cos(),
+    pub fn reflect_safe(&self, normal: &Vec3D) -> Vec3D {
+        match normal.try_normalize() {
+            Ok(normal) => self.reflect(&normal),
+            Err(_) => *self,
         }
     }
-}
 
-#[allow(dead_code)]
-impl Vec2D {
-    /// Creates a new 2D vector
-    pub fn new(x: f64, y: f64) -> Vec2D {
-        Vec2D { x, y }
-    }
+    /// Projects the vector onto a plane while keeping its length
+    ///
+    /// Removes the component along the plane normal and scales the result
+    /// back up to the original length. A vector perpendicular to the plane has
+    /// no direction within it, so the zero vector is returned. A zero normal
+    /// describes no plane and the vector is returned unchanged.
+    ///
+    /// # Arguments
+    /// * `normal` - Normal of the plane, does not need to be normalized
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, 0.0, 4.0);
+    /// let p = v.project_onto_plane_normalized(&Vec3D::unit_z());
+    ///
+    /// assert_eq!(p, Vec3D::new(5.0, 0.0, 0.0));
+    /// ```
+    pub fn project_onto_plane_normalized(&self, normal: &Vec3D) -> Vec3D {
+        let normal = match normal.try_normalize() {
+            Ok(normal) => normal,
+            Err(_) => return *self,
+        };
 
-    /// Returns the length of the vector
-    pub fn length(&self) -> f64 {
-        todo!("Implement this function");
+        let projected = *self - normal * self.dot(&normal);
+        let length = projected.length();
+
+        if length < ZERO_EPSILON {
+            return Vec3D::zero();
+        }
+
+        projected * (self.length() / length)
     }
 
-    /// Updates the vector to a new lengthe
+    /// Mirrors a point across a plane
     ///
-    /// Recalculates the vector to have the given length while maintaining the
-    /// ratio of the components.
-    pub fn update_length(&mut self, _new: f64) {
-        todo!("Implement this function");
-    }
+    /// Unlike [`Vec3D::reflect`], which reflects a direction, this treats the
+    /// vector as a position and the plane does not need to pass through the
+    /// origin.
+    ///
+    /// # Arguments
+    /// * `plane_point` - Any point on the plane
+    /// * `plane_normal` - Normal of the plane, does not need to be normalized
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let p = Vec3D::new(0.0, 0.0, 5.0);
+    /// let mirrored = p.reflect_about_plane(&Vec3D::new(0.0, 0.0, 1.0), &Vec3D::unit_z());
+    ///
+    /// assert_eq!(mirrored, Vec3D::new(0.0, 0.0, -3.0));
+    /// ```
+    pub fn reflect_about_plane(&self, plane_point: &Vec3D, plane_normal: &Vec3D) -> Vec3D {
+        let normal = plane_normal.normalize();
+        let distance = (*self - *plane_point).dot(&normal);
 
-    /// Converts the Cartesian vector to spherical coordinates
-    pub fn to_sphere(&self) -> Vec2DSphere {
-        todo!("Implement this function");
+        *self - normal * (2.0 * distance)
     }
-}
 
-#[cfg(test)]
-mod vec_3d {
-    #[test]
-    fn new() {
-        let v = super::Vec3D::new(1.0, 2.0, 3.0);
+    /// Bounces the vector off a surface, losing energy in the process
+    ///
+    /// The vector is split into a component along the normal and a component
+    /// along the surface. The normal component is reversed and scaled by
+    /// `restitution`, the tangential component is scaled by `1 - friction`.
+    /// With a restitution of 1 and no friction this is the same as
+    /// [`Vec3D::reflect`].
+    ///
+    /// # Arguments
+    /// * `normal` - The surface normal, must be a unit vector
+    /// * `restitution` - Fraction of the normal speed kept, between 0 and 1
+    /// * `friction` - Fraction of the tangential speed lost, between 0 and 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(4.0, 0.0, -2.0);
+    /// let r = v.ricochet(&Vec3D::unit_z(), 0.5, 0.25);
+    ///
+    /// assert_eq!(r.x, 3.0);
+    /// assert_eq!(r.z, 1.0);
+    /// ```
+    pub fn ricochet(&self, normal: &Vec3D, restitution: f64, friction: f64) -> Vec3D {
+        let normal_part = *normal * self.dot(normal);
+        let tangential_part = *self - normal_part;
 
-        assert_eq!(v.x, 1.0);
-        assert_eq!(v.y, 2.0);
-        assert_eq!(v.z, 3.0);
+        tangential_part * (1.0 - friction) - normal_part * restitution
     }
 
-    #[test]
-    fn length() {
-        let v = super::Vec3D::new(3.0, 4.0, 0.0);
-        assert_eq!(v.length(), 5.0, "Length of 3, 4, 0 should be 5");
+    /// Interpolates between two vectors in spherical coordinates
+    ///
+    /// Azimuth, polar angle and radius are interpolated independently, so the
+    /// direction sweeps smoothly while the length changes linearly. The
+    /// azimuth follows the shortest arc between the two vectors.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to interpolate towards
+    /// * `t` - Interpolation factor, 0 returns `self` and 1 returns `other`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(2.0, 0.0, 0.0);
+    /// let b = Vec3D::new(0.0, 2.0, 0.0);
+    /// let mid = a.interpolate_spherical(&b, 0.5);
+    ///
+    /// assert_eq!(mid.length().round(), 2.0);
+    /// assert_eq!(mid.to_sphere().azimuth.to_degrees().round(), 45.0);
+    /// ```
+    pub fn interpolate_spherical(&self, other: &Vec3D, t: f64) -> Vec3D {
+        let from = self.to_sphere();
+        let to = other.to_sphere();
 
-        let v = super::Vec3D::new(1.0, 1.0, 1.0);
-        assert_eq!(
-            v.length(),
-            (1f64 + 1f64 + 1f64).sqrt(),
-            "Length of 1, 1, 1 should be sqrt(3)"
-        );
+        let mut azimuth_delta = (to.azimuth - from.azimuth) % TAU;
+        if azimuth_delta > PI {
+            azimuth_delta -= TAU;
+        } else if azimuth_delta < -PI {
+            azimuth_delta += TAU;
+        }
+
+        Vec3DSphere {
+            azimuth: from.azimuth + azimuth_delta * t,
+            polar: from.polar + (to.polar - from.polar) * t,
+            radius: from.radius + (to.radius - from.radius) * t,
+        }
+        .to_vec()
+    }
+
+    /// Limits the vector to a cone around an axis
+    ///
+    /// Vectors already within `max_half_angle_rad` of `axis` are returned
+    /// unchanged. Others are rotated towards the axis onto the surface of the
+    /// cone, keeping their length.
+    ///
+    /// # Arguments
+    /// * `axis` - Center line of the cone, does not need to be normalized
+    /// * `max_half_angle_rad` - Largest allowed angle from the axis in radians
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let aim = Vec3D::new(0.0, 1.0, 0.0);
+    /// let clamped = aim.clamp_to_cone(&Vec3D::unit_x(), 45f64.to_radians());
+    ///
+    /// assert_eq!(clamped.angle_between(&Vec3D::unit_x()).to_degrees().round(), 45.0);
+    /// assert_eq!(clamped.length(), 1.0);
+    /// ```
+    pub fn clamp_to_cone(&self, axis: &Vec3D, max_half_angle_rad: f64) -> Vec3D {
+        if self.angle_between(axis) <= max_half_angle_rad {
+            return *self;
+        }
+
+        let axis = axis.normalize();
+        let mut side = *self - axis * self.dot(&axis);
+        if side.approx_zero(ZERO_EPSILON) {
+            side = axis.perpendicular();
+        }
+        let side = side.normalize();
+
+        (axis * max_half_angle_rad.cos() + side * max_half_angle_rad.sin()) * self.length()
+    }
+
+    /// Returns the rotation that turns this vector onto the direction of another
+    ///
+    /// The result is a unit rotation axis and an angle in radians, rotating
+    /// counter-clockwise around the axis. For parallel vectors no rotation is
+    /// needed and the angle is 0. For opposite vectors any perpendicular axis
+    /// works and one is picked with an angle of pi. Zero length inputs give a
+    /// zero axis and an angle of 0.
+    ///
+    /// # Arguments
+    /// * `target` - The direction to rotate onto
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let (axis, angle) = Vec3D::unit_x().rotation_to(&Vec3D::unit_y());
+    ///
+    /// assert_eq!(axis, Vec3D::unit_z());
+    /// assert_eq!(angle, 90f64.to_radians());
+    /// ```
+    pub fn rotation_to(&self, target: &Vec3D) -> (Vec3D, f64) {
+        if self.approx_zero(ZERO_EPSILON) || target.approx_zero(ZERO_EPSILON) {
+            return (Vec3D::zero(), 0.0);
+        }
+
+        let angle = self.angle_between(target);
+        let axis = self.cross(target);
+
+        if axis.length() <= ZERO_EPSILON * self.length() * target.length() {
+            return (self.perpendicular(), angle);
+        }
+
+        (axis.normalize(), angle)
+    }
+
+    /// Rotates a point around a line that does not need to pass the origin
+    ///
+    /// The point is moved so the line passes through the origin, rotated
+    /// counter-clockwise around the axis using Rodrigues' formula and moved
+    /// back. An axis shorter than [`ZERO_EPSILON`] describes no line and the
+    /// point is returned unchanged.
+    ///
+    /// # Arguments
+    /// * `point` - Any point on the line
+    /// * `axis` - Direction of the line, does not need to be normalized
+    /// * `angle` - Rotation angle in radians
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let p = Vec3D::new(2.0, 0.0, 0.0);
+    /// let rotated = p.rotate_around_line(&Vec3D::unit_x(), &Vec3D::unit_z(), 90f64.to_radians());
+    ///
+    /// assert_eq!(rotated.round_to(9), Vec3D::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn rotate_around_line(&self, point: &Vec3D, axis: &Vec3D, angle: f64) -> Vec3D {
+        let axis = match axis.try_normalize() {
+            Ok(axis) => axis,
+            Err(_) => return *self,
+        };
+        let local = *self - *point;
+        let (sin, cos) = angle.sin_cos();
+
+        let rotated =
+            local * cos + axis.cross(&local) * sin + axis * (axis.dot(&local) * (1.0 - cos));
+
+        rotated + *point
+    }
+
+    /// Returns an arbitrary unit vector perpendicular to this one
+    fn perpendicular(&self) -> Vec3D {
+        let helper = if self.x.abs() < self.y.abs().max(self.z.abs()) {
+            Vec3D::unit_x()
+        } else {
+            Vec3D::unit_y()
+        };
+
+        self.cross(&helper).normalize()
+    }
+
+    /// Moves a point towards a target by at most a fixed distance
+    ///
+    /// If the target is closer than `max_distance` the target itself is
+    /// returned.
+    ///
+    /// # Arguments
+    /// * `target` - The point to move towards
+    /// * `max_distance` - The furthest the point may move
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let start = Vec3D::zero();
+    /// let target = Vec3D::new(10.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(start.move_towards(&target, 4.0).x, 4.0);
+    /// assert_eq!(start.move_towards(&target, 40.0).x, 10.0);
+    /// ```
+    pub fn move_towards(&self, target: &Vec3D, max_distance: f64) -> Vec3D {
+        let offset = *target - *self;
+        let distance = offset.length();
+
+        if distance <= max_distance {
+            return *target;
+        }
+
+        *self + offset * (max_distance / distance)
+    }
+
+    /// Returns a copy with the x component clamped to a range
+    ///
+    /// # Arguments
+    /// * `min` - Lowest allowed value
+    /// * `max` - Highest allowed value
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(12.0, 2.0, 3.0).clamp_x(-10.0, 10.0);
+    ///
+    /// assert_eq!(v, Vec3D::new(10.0, 2.0, 3.0));
+    /// ```
+    pub fn clamp_x(&self, min: f64, max: f64) -> Vec3D {
+        Vec3D {
+            x: self.x.clamp(min, max),
+            ..*self
+        }
+    }
+
+    /// Returns a copy with the y component clamped to a range
+    ///
+    /// # Arguments
+    /// * `min` - Lowest allowed value
+    /// * `max` - Highest allowed value
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, -7.0, 3.0).clamp_y(-5.0, 5.0);
+    ///
+    /// assert_eq!(v, Vec3D::new(1.0, -5.0, 3.0));
+    /// ```
+    pub fn clamp_y(&self, min: f64, max: f64) -> Vec3D {
+        Vec3D {
+            y: self.y.clamp(min, max),
+            ..*self
+        }
+    }
+
+    /// Returns a copy with the z component clamped to a range
+    ///
+    /// # Arguments
+    /// * `min` - Lowest allowed value
+    /// * `max` - Highest allowed value
+    ///
+    /// # Panics
+    /// Panics if `min > max`, `min` is NaN, or `max` is NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let below_ground = Vec3D::new(10.0, 2.0, -0.5);
+    /// let v = below_ground.clamp_z(0.0, f64::INFINITY);
+    ///
+    /// assert_eq!(v.x, 10.0);
+    /// assert_eq!(v.z, 0.0);
+    /// ```
+    pub fn clamp_z(&self, min: f64, max: f64) -> Vec3D {
+        Vec3D {
+            z: self.z.clamp(min, max),
+            ..*self
+        }
+    }
+
+    /// Cubic Hermite interpolation between two points with known tangents
+    ///
+    /// Gives a smooth curve that passes through `p0` at `t = 0` and `p1` at
+    /// `t = 1`, leaving `p0` with velocity `v0` and arriving at `p1` with
+    /// velocity `v1`. The tangents are per unit of `t`, so velocities sampled
+    /// over a time step `dt` should be scaled by `dt` first.
+    ///
+    /// # Arguments
+    /// * `p0` - Start point
+    /// * `v0` - Tangent at the start point
+    /// * `p1` - End point
+    /// * `v1` - Tangent at the end point
+    /// * `t` - Interpolation factor between 0 and 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let p0 = Vec3D::zero();
+    /// let p1 = Vec3D::new(2.0, 0.0, 0.0);
+    /// let v = Vec3D::new(2.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Vec3D::hermite(&p0, &v, &p1, &v, 0.5), Vec3D::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn hermite(p0: &Vec3D, v0: &Vec3D, p1: &Vec3D, v1: &Vec3D, t: f64) -> Vec3D {
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        *p0 * h00 + *v0 * h10 + *p1 * h01 + *v1 * h11
+    }
+
+    /// Eases the length of the vector towards a maximum
+    ///
+    /// A vector longer than `max` has the excess length reduced by the
+    /// fraction `rate`, keeping its direction. Calling this repeatedly
+    /// converges on `max` instead of snapping to it. Vectors at or below
+    /// `max` are returned unchanged.
+    ///
+    /// # Arguments
+    /// * `max` - The length to ease towards
+    /// * `rate` - Fraction of the excess removed per call, between 0 and 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(20.0, 0.0, 0.0).approach_max_length(10.0, 0.5);
+    ///
+    /// assert_eq!(v, Vec3D::new(15.0, 0.0, 0.0));
+    /// ```
+    pub fn approach_max_length(&self, max: f64, rate: f64) -> Vec3D {
+        let length = self.length();
+
+        if length <= max {
+            return *self;
+        }
+
+        let target = length - (length - max) * rate;
+        *self * (target / length)
+    }
+
+    /// Clamps each component to a symmetric range given by another vector
+    ///
+    /// Each component ends up within [-limit, limit] using the matching
+    /// component of `limits`. The sign of a limit is ignored.
+    ///
+    /// # Arguments
+    /// * `limits` - Largest allowed magnitude for each component
+    ///
+    /// # Panics
+    /// Panics if any component of `limits` is NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(5.0, -5.0, 2.0).clamp_each(&Vec3D::new(3.0, 3.0, 3.0));
+    ///
+    /// assert_eq!(v, Vec3D::new(3.0, -3.0, 2.0));
+    /// ```
+    pub fn clamp_each(&self, limits: &Vec3D) -> Vec3D {
+        Vec3D::new(
+            self.x.clamp(-limits.x.abs(), limits.x.abs()),
+            self.y.clamp(-limits.y.abs(), limits.y.abs()),
+            self.z.clamp(-limits.z.abs(), limits.z.abs()),
+        )
+    }
+
+    /// Rounds each component to the nearest integer
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.4, -2.6, 3.5).round();
+    ///
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, -3.0);
+    /// assert_eq!(v.z, 4.0);
+    /// ```
+    pub fn round(&self) -> Vec3D {
+        Vec3D::new(self.x.round(), self.y.round(), self.z.round())
+    }
+
+    /// Rounds each component to a fixed number of decimals
+    ///
+    /// # Arguments
+    /// * `decimals` - Number of digits to keep after the decimal point
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.2345, 2.0, 3.0).round_to(2);
+    ///
+    /// assert_eq!(v.x, 1.23);
+    /// assert_eq!(v.y, 2.0);
+    /// assert_eq!(v.z, 3.0);
+    /// ```
+    pub fn round_to(&self, decimals: u32) -> Vec3D {
+        let factor = 10f64.powi(decimals as i32);
+        Vec3D::new(
+            (self.x * factor).round() / factor,
+            (self.y * factor).round() / factor,
+            (self.z * factor).round() / factor,
+        )
+    }
+
+    /// Returns the angle in radians between the directions of two vectors
+    ///
+    /// This is the distance along a great circle of the unit sphere and is
+    /// the same as [`Vec3D::angle_between`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let d = Vec3D::unit_x().great_circle_distance(&Vec3D::unit_z());
+    ///
+    /// assert_eq!(d, std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn great_circle_distance(&self, other: &Vec3D) -> f64 {
+        self.angle_between(other)
+    }
+
+    /// Spherical linear interpolation without clamping `t`
+    ///
+    /// Rotates from `self` towards `other` at a constant angular rate. Values
+    /// of `t` outside [0, 1] extrapolate along the same great circle. Vectors
+    /// of equal length keep that length. Nearly parallel vectors fall back to
+    /// linear interpolation. Opposite vectors have no unique great circle, so
+    /// they are rotated through an arbitrary perpendicular direction.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to interpolate towards
+    /// * `t` - Interpolation factor, 0 returns `self` and 1 returns `other`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::unit_x().slerp_unclamped(&Vec3D::unit_y(), 2.0);
+    ///
+    /// assert_eq!(v.round().x, -1.0);
+    /// assert_eq!(v.round().y, 0.0);
+    /// ```
+    pub fn slerp_unclamped(&self, other: &Vec3D, t: f64) -> Vec3D {
+        let angle = self.angle_between(other);
+        let sin = angle.sin();
+
+        if sin.abs() < ZERO_EPSILON {
+            if angle < PI / 2.0 {
+                return *self + (*other - *self) * t;
+            }
+
+            let length = self.length() + (other.length() - self.length()) * t;
+            let direction =
+                self.normalize() * (t * PI).cos() + self.perpendicular() * (t * PI).sin();
+
+            return direction * length;
+        }
+
+        *self * (((1.0 - t) * angle).sin() / sin) + *other * ((t * angle).sin() / sin)
+    }
+
+    /// Returns the sign of each component
+    ///
+    /// Positive components become 1 and negative components -1. Unlike
+    /// [`f64::signum`], zero of either sign becomes 0 so axes without motion
+    /// stay at rest. NaN components stay NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(-2.0, 0.0, 3.0).signum();
+    ///
+    /// assert_eq!(v.x, -1.0);
+    /// assert_eq!(v.y, 0.0);
+    /// assert_eq!(v.z, 1.0);
+    /// ```
+    pub fn signum(&self) -> Vec3D {
+        Vec3D::new(signum(self.x), signum(self.y), signum(self.z))
+    }
+
+    /// Returns the magnitude of each component with the sign of `signs`
+    ///
+    /// Follows [`f64::copysign`], so the sign bit of zero is also copied.
+    ///
+    /// # Arguments
+    /// * `signs` - Vector to take the sign of each component from
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, -2.0, 3.0).copysign(&Vec3D::new(-1.0, 1.0, -0.0));
+    ///
+    /// assert_eq!(v.x, -1.0);
+    /// assert_eq!(v.y, 2.0);
+    /// assert_eq!(v.z, -3.0);
+    /// ```
+    pub fn copysign(&self, signs: &Vec3D) -> Vec3D {
+        Vec3D::new(
+            self.x.copysign(signs.x),
+            self.y.copysign(signs.y),
+            self.z.copysign(signs.z),
+        )
+    }
+
+    /// Converts a vector from East-North-Up to North-East-Down
+    ///
+    /// (east, north, up) becomes (north, east, -up).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let ned = Vec3D::new(1.0, 2.0, 3.0).enu_to_ned();
+    ///
+    /// assert_eq!(ned, Vec3D::new(2.0, 1.0, -3.0));
+    /// ```
+    pub fn enu_to_ned(&self) -> Vec3D {
+        Vec3D::new(self.y, self.x, -self.z)
+    }
+
+    /// Converts a vector from North-East-Down to East-North-Up
+    ///
+    /// (north, east, down) becomes (east, north, -down).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let enu = Vec3D::new(2.0, 1.0, -3.0).ned_to_enu();
+    ///
+    /// assert_eq!(enu, Vec3D::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn ned_to_enu(&self) -> Vec3D {
+        Vec3D::new(self.y, self.x, -self.z)
+    }
+
+    /// Raises each component to an integer power
+    ///
+    /// # Arguments
+    /// * `n` - The exponent
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, -2.0, 3.0).powi(2);
+    ///
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, 4.0);
+    /// assert_eq!(v.z, 9.0);
+    /// ```
+    pub fn powi(&self, n: i32) -> Vec3D {
+        Vec3D::new(self.x.powi(n), self.y.powi(n), self.z.powi(n))
+    }
+
+    /// Raises each component to a floating point power
+    ///
+    /// # Arguments
+    /// * `n` - The exponent
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(8.0, 27.0, 64.0).powf(1.0 / 3.0);
+    ///
+    /// assert_eq!(v.x.round(), 2.0);
+    /// assert_eq!(v.y.round(), 3.0);
+    /// assert_eq!(v.z.round(), 4.0);
+    /// ```
+    pub fn powf(&self, n: f64) -> Vec3D {
+        Vec3D::new(self.x.powf(n), self.y.powf(n), self.z.powf(n))
+    }
+
+    /// Takes the square root of each component
+    ///
+    /// Negative components result in NaN, same as [`f64::sqrt`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(4.0, 9.0, 16.0).sqrt();
+    ///
+    /// assert_eq!(v.x, 2.0);
+    /// assert_eq!(v.y, 3.0);
+    /// assert_eq!(v.z, 4.0);
+    /// ```
+    pub fn sqrt(&self) -> Vec3D {
+        Vec3D::new(self.x.sqrt(), self.y.sqrt(), self.z.sqrt())
+    }
+
+    /// Converts the vector into its 2D projection
+    ///
+    /// The x cordinate is the square root of the sum
+    /// of the squares of the x and y axis.
+    ///
+    /// The y cordinate is the z axis.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, 4.0, 3.0);
+    /// let v2d = v.to_2d();
+    ///
+    /// assert_eq!(v2d.x, 5.0);
+    /// assert_eq!(v2d.y, 3.0);
+    /// ```
+    pub fn to_2d(&self) -> Vec2D {
+        Vec2D {
+            x: self.length_xy(),
+            y: self.z,
+        }
+    }
+
+    /// Returns the dot product of two vectors
+    ///
+    /// x1 * x2 + y1 * y2 + z1 * z2
+    ///
+    /// # Arguments
+    /// * `other` - The vector to take the dot product with
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(1.0, 2.0, 3.0);
+    /// let b = Vec3D::new(4.0, 5.0, 6.0);
+    ///
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
+    pub fn dot(&self, other: &Vec3D) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the cross product of two vectors
+    ///
+    /// The result is perpendicular to both vectors and follows the right hand
+    /// rule.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to take the cross product with
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let x = Vec3D::new(1.0, 0.0, 0.0);
+    /// let y = Vec3D::new(0.0, 1.0, 0.0);
+    /// let z = x.cross(&y);
+    ///
+    /// assert_eq!(z.x, 0.0);
+    /// assert_eq!(z.y, 0.0);
+    /// assert_eq!(z.z, 1.0);
+    /// ```
+    pub fn cross(&self, other: &Vec3D) -> Vec3D {
+        Vec3D {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// Returns the scalar triple product `a · (b × c)`
+    ///
+    /// Equal to the signed volume of the parallelepiped spanned by the three
+    /// vectors, so it is zero when they are coplanar.
+    ///
+    /// # Arguments
+    /// * `a` - First vector
+    /// * `b` - Second vector
+    /// * `c` - Third vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let volume = Vec3D::scalar_triple(
+    ///     &Vec3D::new(2.0, 0.0, 0.0),
+    ///     &Vec3D::new(0.0, 3.0, 0.0),
+    ///     &Vec3D::new(0.0, 0.0, 4.0),
+    /// );
+    ///
+    /// assert_eq!(volume, 24.0);
+    /// ```
+    pub fn scalar_triple(a: &Vec3D, b: &Vec3D, c: &Vec3D) -> f64 {
+        a.dot(&b.cross(c))
+    }
+
+    /// Returns the vector triple product `a × (b × c)`
+    ///
+    /// The result lies in the plane spanned by `b` and `c`.
+    ///
+    /// # Arguments
+    /// * `a` - First vector
+    /// * `b` - Second vector
+    /// * `c` - Third vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::vector_triple(&Vec3D::unit_x(), &Vec3D::unit_x(), &Vec3D::unit_y());
+    ///
+    /// assert_eq!(v, Vec3D::new(0.0, -1.0, 0.0));
+    /// ```
+    pub fn vector_triple(a: &Vec3D, b: &Vec3D, c: &Vec3D) -> Vec3D {
+        a.cross(&b.cross(c))
+    }
+
+    /// Converts the Cartesian vector to spherical coordinates
+    ///
+    /// Vectors shorter than [`ZERO_EPSILON`] have no direction and are given
+    /// angles of 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 1.0, 1.0);
+    /// let v_sphere = v.to_sphere();
+    ///
+    /// assert_eq!(v_sphere.radius, (1f64 + 1f64 + 1f64).sqrt());
+    /// assert_eq!(v_sphere.azimuth, 45f64.to_radians());
+    /// ```
+    pub fn to_sphere(&self) -> Vec3DSphere {
+        if self.approx_zero(ZERO_EPSILON) {
+            return Vec3DSphere {
+                radius: self.length(),
+                azimuth: 0.0,
+                polar: 0.0,
+            };
+        }
+
+        Vec3DSphere {
+            radius: self.length(),
+            azimuth: self.y.atan2(self.x),
+            polar: self.length_xy().atan2(self.z),
+        }
+    }
+
+    /// Converts the Cartesian vector to spherical coordinates
+    ///
+    /// Checked version of [`Vec3D::to_sphere`].
+    ///
+    /// # Errors
+    /// Returns [`VectorError::ZeroLength`] for vectors shorter than
+    /// [`ZERO_EPSILON`], whose angles are undefined.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::{Vec3D, VectorError};
+    /// let v = Vec3D::new(0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(v.try_to_sphere().err(), Some(VectorError::ZeroLength));
+    /// ```
+    pub fn try_to_sphere(&self) -> Result<Vec3DSphere, VectorError> {
+        if self.approx_zero(ZERO_EPSILON) {
+            return Err(VectorError::ZeroLength);
+        }
+
+        Ok(self.to_sphere())
+    }
+
+    /// Returns a vector with the same direction and a length of 1
+    ///
+    /// Vectors shorter than [`ZERO_EPSILON`] have no direction and normalize
+    /// to the zero vector, use [`Vec3D::try_normalize`] to detect that case.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(3.0, 0.0, 4.0).normalize();
+    ///
+    /// assert_eq!(v.x, 0.6);
+    /// assert_eq!(v.z, 0.8);
+    /// ```
+    pub fn normalize(&self) -> Vec3D {
+        if self.approx_zero(ZERO_EPSILON) {
+            return Vec3D::zero();
+        }
+
+        let length = self.length();
+        Vec3D::new(self.x / length, self.y / length, self.z / length)
+    }
+
+    /// Returns a vector with the same direction and a length of 1
+    ///
+    /// Checked version of [`Vec3D::normalize`].
+    ///
+    /// # Errors
+    /// Returns [`VectorError::ZeroLength`] for vectors shorter than
+    /// [`ZERO_EPSILON`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(0.0, 2.0, 0.0).try_normalize().unwrap();
+    ///
+    /// assert_eq!(v.y, 1.0);
+    /// ```
+    pub fn try_normalize(&self) -> Result<Vec3D, VectorError> {
+        if self.approx_zero(ZERO_EPSILON) {
+            return Err(VectorError::ZeroLength);
+        }
+
+        Ok(self.normalize())
+    }
+
+    /// Compares two vectors component by component using IEEE 754 equality
+    ///
+    /// Same as the derived `PartialEq`: `+0.0` equals `-0.0` and a NaN
+    /// component never equals anything, including itself.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(0.0, 1.0, 2.0);
+    /// let b = Vec3D::new(-0.0, 1.0, 2.0);
+    ///
+    /// assert!(a.exact_eq(&b));
+    /// ```
+    pub fn exact_eq(&self, other: &Vec3D) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+
+    /// Compares the raw bit patterns of two vectors
+    ///
+    /// Unlike [`Vec3D::exact_eq`], `+0.0` and `-0.0` are different while NaN
+    /// components with the same bits are equal. This is the comparison to
+    /// use for deterministic deduplication or hashing.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(0.0, 1.0, f64::NAN);
+    ///
+    /// assert!(a.bit_eq(&a));
+    /// assert!(!a.bit_eq(&Vec3D::new(-0.0, 1.0, f64::NAN)));
+    /// ```
+    pub fn bit_eq(&self, other: &Vec3D) -> bool {
+        self.x.to_bits() == other.x.to_bits()
+            && self.y.to_bits() == other.y.to_bits()
+            && self.z.to_bits() == other.z.to_bits()
+    }
+
+    /// Returns true if the vector is shorter than `epsilon`
+    ///
+    /// # Arguments
+    /// * `epsilon` - Lengths below this are considered zero
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1e-9, 0.0, 0.0);
+    ///
+    /// assert!(v.approx_zero(1e-6));
+    /// assert!(!v.approx_zero(1e-12));
+    /// ```
+    pub fn approx_zero(&self, epsilon: f64) -> bool {
+        self.length() < epsilon
+    }
+
+    /// Returns the angle between two vectors in radians
+    ///
+    /// The result is in the range [0, pi]. If either vector is shorter than
+    /// [`ZERO_EPSILON`] the angle is undefined and 0 is returned.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to measure the angle to
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(1.0, 0.0, 0.0);
+    /// let b = Vec3D::new(0.0, 3.0, 0.0);
+    ///
+    /// assert_eq!(a.angle_between(&b), 90f64.to_radians());
+    /// ```
+    pub fn angle_between(&self, other: &Vec3D) -> f64 {
+        if self.approx_zero(ZERO_EPSILON) || other.approx_zero(ZERO_EPSILON) {
+            return 0.0;
+        }
+
+        let cos = self.dot(other) / (self.length() * other.length());
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    /// Returns the angle between the vector and the xy plane in radians
+    ///
+    /// Positive when the vector points above the plane and negative below it.
+    /// The zero vector returns 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(v.angle_to_horizontal(), 45f64.to_radians());
+    /// ```
+    pub fn angle_to_horizontal(&self) -> f64 {
+        if self.approx_zero(ZERO_EPSILON) {
+            return 0.0;
+        }
+
+        self.z.atan2(self.length_xy())
+    }
+
+    /// Returns the angle between the vector and an axis in radians
+    ///
+    /// Same as [`Vec3D::angle_between`], the result is in the range [0, pi].
+    ///
+    /// # Arguments
+    /// * `axis` - The direction to measure from, does not need to be normalized
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(0.0, 0.0, 2.0);
+    /// let x_axis = Vec3D::new(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(v.angle_to_axis(&x_axis), 90f64.to_radians());
+    /// ```
+    pub fn angle_to_axis(&self, axis: &Vec3D) -> f64 {
+        self.angle_between(axis)
+    }
+
+    /// Returns true if two vectors point in the same direction
+    ///
+    /// Magnitudes are ignored. A vector shorter than [`ZERO_EPSILON`] has no
+    /// direction and is never considered to share one.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to compare against
+    /// * `epsilon` - Largest angle in radians still considered the same
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let a = Vec3D::new(1.0, 0.0, 0.0);
+    ///
+    /// assert!(a.same_direction(&Vec3D::new(5.0, 0.0, 0.0), 1e-9));
+    /// assert!(!a.same_direction(&Vec3D::new(-1.0, 0.0, 0.0), 1e-9));
+    /// ```
+    pub fn same_direction(&self, other: &Vec3D, epsilon: f64) -> bool {
+        if self.approx_zero(ZERO_EPSILON) || other.approx_zero(ZERO_EPSILON) {
+            return false;
+        }
+
+        self.angle_between(other) <= epsilon
+    }
+}
+
+impl Add for Vec3D {
+    type Output = Vec3D;
+
+    fn add(self, other: Vec3D) -> Vec3D {
+        Vec3D::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vec3D {
+    type Output = Vec3D;
+
+    fn sub(self, other: Vec3D) -> Vec3D {
+        Vec3D::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f64> for Vec3D {
+    type Output = Vec3D;
+
+    fn mul(self, scalar: f64) -> Vec3D {
+        Vec3D::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+/// Componentwise (Hadamard) multiplication
+///
+/// Use [`Vec3D::dot`] or [`Vec3D::cross`] for the dot and cross products.
+impl Mul<Vec3D> for Vec3D {
+    type Output = Vec3D;
+
+    fn mul(self, other: Vec3D) -> Vec3D {
+        self.hadamard(&other)
+    }
+}
+
+impl From<(f64, f64, f64)> for Vec3D {
+    /// Creates a vector from an (x, y, z) tuple
+    fn from((x, y, z): (f64, f64, f64)) -> Vec3D {
+        Vec3D::new(x, y, z)
+    }
+}
+
+impl From<Vec3D> for (f64, f64, f64) {
+    /// Destructures a vector into an (x, y, z) tuple
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let (x, y, z): (f64, f64, f64) = Vec3D::new(1.0, 2.0, 3.0).into();
+    ///
+    /// assert_eq!((x, y, z), (1.0, 2.0, 3.0));
+    /// ```
+    fn from(v: Vec3D) -> (f64, f64, f64) {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl TryFrom<&[f64]> for Vec3D {
+    type Error = VectorError;
+
+    /// Creates a vector from a slice of exactly 3 components
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::{Vec3D, VectorError};
+    /// let v = Vec3D::try_from(&[1.0, 2.0, 3.0][..]).unwrap();
+    /// assert_eq!(v.z, 3.0);
+    ///
+    /// let err = Vec3D::try_from(&[1.0, 2.0][..]).unwrap_err();
+    /// assert_eq!(err, VectorError::LengthMismatch { expected: 3, actual: 2 });
+    /// ```
+    fn try_from(components: &[f64]) -> Result<Vec3D, VectorError> {
+        match components {
+            [x, y, z] => Ok(Vec3D::new(*x, *y, *z)),
+            _ => Err(VectorError::LengthMismatch {
+                expected: 3,
+                actual: components.len(),
+            }),
+        }
+    }
+}
+
+impl IntoIterator for Vec3D {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 3>;
+
+    /// Iterates over the components in x, y, z order
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3D;
+    /// let v = Vec3D::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.into_iter().sum::<f64>(), 6.0);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y, self.z].into_iter()
+    }
+}
+
+impl IntoIterator for &Vec3D {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+impl Vec3DSphere {
+    /// Converts the spherical vector to a Cartesian vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v_sphere = Vec3DSphere {
+    ///     radius: 5.0,
+    ///     azimuth: 45f64.to_radians(),
+    ///     polar: 45f64.to_radians(),
+    /// };
+    ///
+    /// let v = v_sphere.to_vec().to_sphere();
+    ///
+    /// assert_eq!(v.radius, 5.0);
+    /// assert_eq!(v.azimuth.to_degrees().round(), 45.);
+    /// assert_eq!(v.polar.to_degrees().round(), 45.);
+    /// ```
+    pub fn to_vec(&self) -> Vec3D {
+        Vec3D {
+            x: self.radius * self.azimuth.cos() * self.polar.sin(),
+            y: self.radius * self.azimuth.sin() * self.polar.sin(),
+            z: self.radius * self.polar.cos(),
+        }
+    }
+
+    /// Returns a copy with the radius multiplied by `factor`
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v = Vec3DSphere {
+    ///     radius: 5.0,
+    ///     azimuth: 0.5,
+    ///     polar: 1.0,
+    /// };
+    ///
+    /// assert_eq!(v.scale_radius(2.0).radius, 10.0);
+    /// ```
+    pub fn scale_radius(&self, factor: f64) -> Vec3DSphere {
+        Vec3DSphere {
+            radius: self.radius * factor,
+            ..*self
+        }
+        .normalized()
+    }
+
+    /// Returns a copy rotated around the z axis by `delta` radians
+    ///
+    /// The resulting azimuth is wrapped into the range (-pi, pi].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v = Vec3DSphere {
+    ///     radius: 1.0,
+    ///     azimuth: 170f64.to_radians(),
+    ///     polar: 1.0,
+    /// };
+    ///
+    /// let rotated = v.rotate_azimuth(20f64.to_radians());
+    /// assert_eq!(rotated.azimuth.to_degrees().round(), -170.0);
+    /// ```
+    pub fn rotate_azimuth(&self, delta: f64) -> Vec3DSphere {
+        Vec3DSphere {
+            azimuth: self.azimuth + delta,
+            ..*self
+        }
+        .normalized()
+    }
+
+    /// Returns a copy tilted away from the z axis by `delta` radians
+    ///
+    /// Tilting past either pole continues down the other side, so the polar
+    /// angle stays within [0, pi] and the azimuth is flipped as needed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v = Vec3DSphere {
+    ///     radius: 1.0,
+    ///     azimuth: 0.0,
+    ///     polar: 10f64.to_radians(),
+    /// };
+    ///
+    /// let tilted = v.tilt_polar(-20f64.to_radians());
+    /// assert_eq!(tilted.polar.to_degrees().round(), 10.0);
+    /// assert_eq!(tilted.azimuth.to_degrees().round(), 180.0);
+    /// ```
+    pub fn tilt_polar(&self, delta: f64) -> Vec3DSphere {
+        Vec3DSphere {
+            polar: self.polar + delta,
+            ..*self
+        }
+        .normalized()
+    }
+
+    /// Returns the polar angle, or 0 for a degenerate vector
+    ///
+    /// A radius shorter than [`ZERO_EPSILON`] has no meaningful direction, and
+    /// angles computed from one may be NaN. Both cases return 0 instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v = Vec3DSphere {
+    ///     radius: 0.0,
+    ///     azimuth: f64::NAN,
+    ///     polar: f64::NAN,
+    /// };
+    ///
+    /// assert_eq!(v.safe_polar(), 0.0);
+    /// ```
+    pub fn safe_polar(&self) -> f64 {
+        self.safe_angle(self.polar)
+    }
+
+    /// Returns the azimuth, or 0 for a degenerate vector
+    ///
+    /// See [`Vec3DSphere::safe_polar`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec3DSphere;
+    /// let v = Vec3DSphere {
+    ///     radius: 2.0,
+    ///     azimuth: 1.5,
+    ///     polar: 0.5,
+    /// };
+    ///
+    /// assert_eq!(v.safe_azimuth(), 1.5);
+    /// ```
+    pub fn safe_azimuth(&self) -> f64 {
+        self.safe_angle(self.azimuth)
+    }
+
+    /// Returns `angle`, or 0 when the radius is too short or the angle is NaN
+    fn safe_angle(&self, angle: f64) -> f64 {
+        if self.radius.abs() < ZERO_EPSILON || angle.is_nan() {
+            0.0
+        } else {
+            angle
+        }
+    }
+
+    /// Returns an equivalent vector with canonical angles
+    ///
+    /// The radius is made non-negative, the polar angle is brought into
+    /// [0, pi] and the azimuth into (-pi, pi].
+    fn normalized(&self) -> Vec3DSphere {
+        let mut radius = self.radius;
+        let mut azimuth = self.azimuth;
+        let mut polar = self.polar.rem_euclid(TAU);
+
+        if polar > PI {
+            polar = TAU - polar;
+            azimuth += PI;
+        }
+
+        if radius < 0.0 {
+            radius = -radius;
+            polar = PI - polar;
+            azimuth += PI;
+        }
+
+        azimuth = PI - (PI - azimuth).rem_euclid(TAU);
+
+        Vec3DSphere {
+            radius,
+            azimuth,
+            polar,
+        }
+    }
+}
+
+impl fmt::Display for Vec3DSphere {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "(radius: {}, azimuth: {}, polar: {})",
+            self.radius,
+            self.safe_azimuth(),
+            self.safe_polar()
+        )
+    }
+}
+
+#[allow(dead_code)]
+impl Vec2DSphere {
+    /// Converts the polar vector to a Cartesian vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2DSphere;
+    /// let v = Vec2DSphere {
+    ///     polar: 0.0,
+    ///     radius: 2.0,
+    /// }
+    /// .to_vec();
+    ///
+    /// assert_eq!(v.x, 2.0);
+    /// assert_eq!(v.y, 0.0);
+    /// ```
+    pub fn to_vec(&self) -> Vec2D {
+        Vec2D {
+            x: self.radius * self.polar.cos(),
+            y: self.radius * self.polar.sin(),
+        }
+    }
+
+    /// Brings the vector into canonical form in place
+    ///
+    /// A negative radius is made positive by turning the angle half a
+    /// revolution, then the polar angle is wrapped into [0, 2pi). The
+    /// Cartesian vector is unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2DSphere;
+    /// use std::f64::consts::PI;
+    /// let mut v = Vec2DSphere {
+    ///     polar: -PI / 2.0,
+    ///     radius: -1.0,
+    /// };
+    /// v.normalize();
+    ///
+    /// assert_eq!(v.radius, 1.0);
+    /// assert!((v.polar - PI / 2.0).abs() < 1e-12);
+    /// ```
+    pub fn normalize(&mut self) {
+        if self.radius < 0.0 {
+            self.radius = -self.radius;
+            self.polar += PI;
+        }
+
+        self.polar = self.polar.rem_euclid(TAU);
+
+        // rem_euclid can round up to TAU for tiny negative angles
+        if self.polar >= TAU {
+            self.polar = 0.0;
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Vec2D {
+    /// Creates a new 2D vector
+    pub const fn new(x: f64, y: f64) -> Vec2D {
+        Vec2D { x, y }
+    }
+
+    /// Creates a vector with all components set to 0
+    pub const fn zero() -> Vec2D {
+        Vec2D::new(0.0, 0.0)
+    }
+
+    /// Creates a unit vector along the x axis
+    pub const fn unit_x() -> Vec2D {
+        Vec2D::new(1.0, 0.0)
+    }
+
+    /// Creates a unit vector along the y axis
+    pub const fn unit_y() -> Vec2D {
+        Vec2D::new(0.0, 1.0)
+    }
+
+    /// Creates a new 2D vector from an angle
+    ///
+    /// The angle is measured from the x axis towards the y axis.
+    ///
+    /// # Arguments
+    /// * `angle` - Angle from the x axis
+    /// * `magnitude` - The length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// use ballistics_calculator::units::Angle;
+    /// let v = Vec2D::from_angle(Angle::radians(0.0), 3.0);
+    ///
+    /// assert_eq!(v.x, 3.0);
+    /// assert_eq!(v.y, 0.0);
+    /// ```
+    pub fn from_angle(angle: impl Into<Angle>, magnitude: f64) -> Vec2D {
+        let angle = angle.into().as_radians();
+
+        Vec2D {
+            x: magnitude * angle.cos(),
+            y: magnitude * angle.sin(),
+        }
+    }
+
+    /// Creates a new 2D vector from an angle in degrees
+    ///
+    /// Same as [`Vec2D::from_angle`] with the angle given in degrees.
+    ///
+    /// # Arguments
+    /// * `angle_deg` - Angle from the x axis in degrees
+    /// * `magnitude` - The length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::from_angle_deg(90.0, 1.0);
+    ///
+    /// assert!(v.x.abs() < 1e-12);
+    /// assert_eq!(v.y, 1.0);
+    /// ```
+    pub fn from_angle_deg(angle_deg: f64, magnitude: f64) -> Vec2D {
+        Vec2D::from_angle(Angle::degrees(angle_deg), magnitude)
+    }
+
+    /// Returns a copy of the vector with the x component replaced
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 2.0).with_x(5.0);
+    ///
+    /// assert_eq!(v.x, 5.0);
+    /// assert_eq!(v.y, 2.0);
+    /// ```
+    pub fn with_x(self, x: f64) -> Vec2D {
+        Vec2D { x, ..self }
+    }
+
+    /// Returns a copy of the vector with the y component replaced
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 2.0).with_y(5.0);
+    ///
+    /// assert_eq!(v.x, 1.0);
+    /// assert_eq!(v.y, 5.0);
+    /// ```
+    pub fn with_y(self, y: f64) -> Vec2D {
+        Vec2D { y, ..self }
+    }
+
+    /// Returns the length of the vector
+    ///
+    /// The length is calculated using the Pythagorean theorem.
+    /// sqrt(x^2 + y^2)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(3.0, 4.0);
+    /// assert_eq!(v.length(), 5.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns the squared length of the vector
+    ///
+    /// x^2 + y^2
+    pub fn length_squared(&self) -> f64 {
+        self.x.powi(2) + self.y.powi(2)
+    }
+
+    /// Returns true if the length of the vector is within `epsilon` of 1
+    ///
+    /// # Arguments
+    /// * `epsilon` - Allowed difference from a length of 1
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// assert!(Vec2D::from_angle_deg(30.0, 1.0).is_unit(1e-12));
+    /// assert!(!Vec2D::new(0.0, 2.0).is_unit(1e-12));
+    /// ```
+    pub fn is_unit(&self, epsilon: f64) -> bool {
+        let squared = self.length_squared();
+        (1.0 - epsilon).max(0.0).powi(2) <= squared && squared <= (1.0 + epsilon).powi(2)
+    }
+
+    /// Returns the Manhattan (L1) length of the vector
+    ///
+    /// |x| + |y|
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(3.0, -4.0);
+    /// assert_eq!(v.manhattan_length(), 7.0);
+    /// ```
+    pub fn manhattan_length(&self) -> f64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    /// Returns the Chebyshev (L-infinity) length of the vector
+    ///
+    /// max(|x|, |y|)
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(3.0, -4.0);
+    /// assert_eq!(v.chebyshev_length(), 4.0);
+    /// ```
+    pub fn chebyshev_length(&self) -> f64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Updates the vector to a new lengthe
+    ///
+    /// Recalculates the vector to have the given length while maintaining the
+    /// ratio of the components. As with [`Vec3D::update_length`] the absolute
+    /// value of `new` is used and a vector shorter than [`ZERO_EPSILON`] is
+    /// left unchanged.
+    ///
+    /// # Arguments
+    /// * `new` - The new length of the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let mut v = Vec2D::new(3.0, 4.0);
+    ///
+    /// v.update_length(10.0);
+    /// assert_eq!(v, Vec2D::new(6.0, 8.0));
+    /// ```
+    pub fn update_length(&mut self, new: f64) {
+        let length = self.length();
+
+        if length < ZERO_EPSILON {
+            return;
+        }
+
+        *self *= new.abs() / length;
+    }
+
+    /// Converts the Cartesian vector to spherical coordinates
+    ///
+    /// Vectors shorter than [`ZERO_EPSILON`] have no direction and are given
+    /// an angle of 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(0.0, 2.0).to_sphere();
+    ///
+    /// assert_eq!(v.radius, 2.0);
+    /// assert_eq!(v.polar, 90f64.to_radians());
+    /// ```
+    pub fn to_sphere(&self) -> Vec2DSphere {
+        let radius = self.length();
+
+        if radius < ZERO_EPSILON {
+            return Vec2DSphere { polar: 0.0, radius };
+        }
+
+        Vec2DSphere {
+            polar: self.y.atan2(self.x),
+            radius,
+        }
+    }
+
+    /// Returns the sign of each component
+    ///
+    /// Same as [`Vec3D::signum`], zero components stay 0.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(-0.5, 0.0).signum();
+    ///
+    /// assert_eq!(v.x, -1.0);
+    /// assert_eq!(v.y, 0.0);
+    /// ```
+    pub fn signum(&self) -> Vec2D {
+        Vec2D::new(signum(self.x), signum(self.y))
+    }
+
+    /// Returns the magnitude of each component with the sign of `signs`
+    ///
+    /// Follows [`f64::copysign`], so the sign bit of zero is also copied.
+    ///
+    /// # Arguments
+    /// * `signs` - Vector to take the sign of each component from
+    pub fn copysign(&self, signs: &Vec2D) -> Vec2D {
+        Vec2D::new(self.x.copysign(signs.x), self.y.copysign(signs.y))
+    }
+
+    /// Places a range/height sample in 3D along a bearing
+    ///
+    /// `x` is treated as the downrange distance and `y` as the height, giving
+    /// (x * cos(azimuth), x * sin(azimuth), y).
+    ///
+    /// # Arguments
+    /// * `azimuth_rad` - Bearing from the x axis towards the y axis in radians
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(100.0, 5.0).to_3d(0.0);
+    ///
+    /// assert_eq!(v.x, 100.0);
+    /// assert_eq!(v.y, 0.0);
+    /// assert_eq!(v.z, 5.0);
+    /// ```
+    pub fn to_3d(&self, azimuth_rad: f64) -> Vec3D {
+        Vec3D {
+            x: self.x * azimuth_rad.cos(),
+            y: self.x * azimuth_rad.sin(),
+            z: self.y,
+        }
+    }
+
+    /// Returns the vector rotated 90 degrees counter-clockwise
+    ///
+    /// This is the left hand normal, (-y, x).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 0.0).perp();
+    ///
+    /// assert_eq!(v.x, 0.0);
+    /// assert_eq!(v.y, 1.0);
+    /// ```
+    pub fn perp(&self) -> Vec2D {
+        Vec2D {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Returns the vector rotated 90 degrees clockwise
+    ///
+    /// This is the right hand normal, (y, -x).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 0.0).perp_cw();
+    ///
+    /// assert_eq!(v.x, 0.0);
+    /// assert_eq!(v.y, -1.0);
+    /// ```
+    pub fn perp_cw(&self) -> Vec2D {
+        Vec2D {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// Returns the perp-dot product of two vectors
+    ///
+    /// Also known as the 2D cross product, x1 * y2 - y1 * x2. It is positive
+    /// when `other` is counter-clockwise from `self`, negative when it is
+    /// clockwise and zero when the vectors are collinear.
+    ///
+    /// # Arguments
+    /// * `other` - The vector to compare against
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let a = Vec2D::new(1.0, 0.0);
+    /// let b = Vec2D::new(0.0, 1.0);
+    ///
+    /// assert_eq!(a.perp_dot(&b), 1.0);
+    /// assert_eq!(b.perp_dot(&a), -1.0);
+    /// ```
+    pub fn perp_dot(&self, other: &Vec2D) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the 3D cross product of two vectors lying in the xy plane
+    ///
+    /// The result points along the z axis with a length equal to
+    /// [`Vec2D::perp_dot`], (0, 0, x1 * y2 - y1 * x2).
+    ///
+    /// # Arguments
+    /// * `other` - The vector to take the cross product with
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::{Vec2D, Vec3D};
+    /// let z = Vec2D::unit_x().cross_z(&Vec2D::unit_y());
+    ///
+    /// assert_eq!(z, Vec3D::unit_z());
+    /// ```
+    pub fn cross_z(&self, other: &Vec2D) -> Vec3D {
+        Vec3D::new(0.0, 0.0, self.perp_dot(other))
+    }
+}
+
+impl Add for Vec2D {
+    type Output = Vec2D;
+
+    fn add(self, other: Vec2D) -> Vec2D {
+        Vec2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<(f64, f64)> for Vec2D {
+    type Output = Vec2D;
+
+    /// Adds an (x, y) offset to the vector
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 1.0) + (2.0, 3.0);
+    ///
+    /// assert_eq!(v, Vec2D::new(3.0, 4.0));
+    /// ```
+    fn add(self, (x, y): (f64, f64)) -> Vec2D {
+        Vec2D::new(self.x + x, self.y + y)
+    }
+}
+
+impl Sub for Vec2D {
+    type Output = Vec2D;
+
+    fn sub(self, other: Vec2D) -> Vec2D {
+        Vec2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vec2D {
+    type Output = Vec2D;
+
+    fn mul(self, scalar: f64) -> Vec2D {
+        Vec2D::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl AddAssign for Vec2D {
+    fn add_assign(&mut self, other: Vec2D) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<(f64, f64)> for Vec2D {
+    fn add_assign(&mut self, offset: (f64, f64)) {
+        *self = *self + offset;
+    }
+}
+
+impl MulAssign<f64> for Vec2D {
+    fn mul_assign(&mut self, scalar: f64) {
+        *self = *self * scalar;
+    }
+}
+
+impl From<(f64, f64)> for Vec2D {
+    /// Creates a vector from an (x, y) tuple
+    fn from((x, y): (f64, f64)) -> Vec2D {
+        Vec2D::new(x, y)
+    }
+}
+
+impl From<Vec2D> for (f64, f64) {
+    /// Destructures a vector into an (x, y) tuple
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let (x, y): (f64, f64) = Vec2D::new(1.0, 2.0).into();
+    ///
+    /// assert_eq!((x, y), (1.0, 2.0));
+    /// ```
+    fn from(v: Vec2D) -> (f64, f64) {
+        (v.x, v.y)
+    }
+}
+
+impl TryFrom<&[f64]> for Vec2D {
+    type Error = VectorError;
+
+    /// Creates a vector from a slice of exactly 2 components
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::try_from(&[1.0, 2.0][..]).unwrap();
+    /// assert_eq!(v.y, 2.0);
+    ///
+    /// assert!(Vec2D::try_from(&[1.0, 2.0, 3.0][..]).is_err());
+    /// ```
+    fn try_from(components: &[f64]) -> Result<Vec2D, VectorError> {
+        match components {
+            [x, y] => Ok(Vec2D::new(*x, *y)),
+            _ => Err(VectorError::LengthMismatch {
+                expected: 2,
+                actual: components.len(),
+            }),
+        }
+    }
+}
+
+impl IntoIterator for Vec2D {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 2>;
+
+    /// Iterates over the components in x, y order
+    ///
+    /// # Examples
+    /// ```rust
+    /// use ballistics_calculator::types::Vec2D;
+    /// let v = Vec2D::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.into_iter().collect::<Vec<f64>>(), vec![1.0, 2.0]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y].into_iter()
+    }
+}
+
+impl IntoIterator for &Vec2D {
+    type Item = f64;
+    type IntoIter = std::array::IntoIter<f64, 2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+/// Sign of a single component, keeping zero as zero
+fn signum(value: f64) -> f64 {
+    if value == 0.0 {
+        0.0
+    } else {
+        value.signum()
+    }
+}
+
+#[cfg(feature = "approx")]
+impl AbsDiffEq for Vec3D {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Vec3D, epsilon: f64) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl RelativeEq for Vec3D {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Vec3D, epsilon: f64, max_relative: f64) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl AbsDiffEq for Vec2D {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Vec2D, epsilon: f64) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl RelativeEq for Vec2D {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Vec2D, epsilon: f64, max_relative: f64) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+#[cfg(test)]
+mod vec_3d {
+    #[test]
+    fn new() {
+        let v = super::Vec3D::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.x, 1.0);
+        assert_eq!(v.y, 2.0);
+        assert_eq!(v.z, 3.0);
+    }
+
+    #[test]
+    fn length() {
+        let v = super::Vec3D::new(3.0, 4.0, 0.0);
+        assert_eq!(v.length(), 5.0, "Length of 3, 4, 0 should be 5");
+
+        let v = super::Vec3D::new(1.0, 1.0, 1.0);
+        assert_eq!(
+            v.length(),
+            (1f64 + 1f64 + 1f64).sqrt(),
+            "Length of 1, 1, 1 should be sqrt(3)"
+        );
+    }
+    #[test]
+    fn dot() {
+        let a = super::Vec3D::new(1.0, 0.0, 0.0);
+        let b = super::Vec3D::new(0.0, 1.0, 0.0);
+        assert_eq!(
+            a.dot(&b),
+            0.0,
+            "Perpendicular vectors should have a dot product of 0"
+        );
+
+        let a = super::Vec3D::new(1.0, 2.0, 3.0);
+        assert_eq!(
+            a.dot(&a),
+            14.0,
+            "Dot product with itself should be the squared length"
+        );
+    }
+
+    #[test]
+    fn cross() {
+        let a = super::Vec3D::new(1.0, 2.0, 3.0);
+        let b = super::Vec3D::new(4.0, 5.0, 6.0);
+        let c = a.cross(&b);
+
+        assert_eq!(c.x, -3.0);
+        assert_eq!(c.y, 6.0);
+        assert_eq!(c.z, -3.0);
+        assert_eq!(c.dot(&a), 0.0, "Cross product should be perpendicular to a");
+        assert_eq!(c.dot(&b), 0.0, "Cross product should be perpendicular to b");
+    }
+
+    #[test]
+    fn ops() {
+        let a = super::Vec3D::new(1.0, 2.0, 3.0);
+        let b = super::Vec3D::new(4.0, 5.0, 6.0);
+
+        let sum = a + b;
+        assert_eq!((sum.x, sum.y, sum.z), (5.0, 7.0, 9.0));
+
+        let diff = b - a;
+        assert_eq!((diff.x, diff.y, diff.z), (3.0, 3.0, 3.0));
+
+        let scaled = a * 2.0;
+        assert_eq!((scaled.x, scaled.y, scaled.z), (2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn from_angles_deg() {
+        let v = super::Vec3D::from_angles_deg(30.0, 20.0, 10.0);
+        let expected = super::Vec3DSphere {
+            azimuth: 30f64.to_radians(),
+            polar: 70f64.to_radians(),
+            radius: 10.0,
+        }
+        .to_vec();
+
+        assert!((v.x - expected.x).abs() < 1e-12);
+        assert!((v.y - expected.y).abs() < 1e-12);
+        assert!((v.z - expected.z).abs() < 1e-12);
+        assert!((v.length() - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn into_iter() {
+        let v = super::Vec3D::new(1.0, 2.0, 3.0);
+
+        let by_ref: Vec<f64> = (&v).into_iter().collect();
+        assert_eq!(by_ref, vec![1.0, 2.0, 3.0]);
+
+        let by_value: Vec<f64> = v.into_iter().collect();
+        assert_eq!(by_value, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn with_components() {
+        let v = super::Vec3D::new(1.0, 2.0, 3.0);
+        let ground = v.with_z(0.0);
+
+        assert_eq!((ground.x, ground.y, ground.z), (1.0, 2.0, 0.0));
+        assert_eq!(
+            (v.x, v.y, v.z),
+            (1.0, 2.0, 3.0),
+            "Original should be unchanged"
+        );
+
+        let v = v.with_x(-1.0).with_y(-2.0);
+        assert_eq!((v.x, v.y, v.z), (-1.0, -2.0, 3.0));
+    }
+
+    #[test]
+    fn from_angles() {
+        use crate::units::Angle;
+
+        let typed = super::Vec3D::from_angles(Angle::radians(0.5), Angle::degrees(20.0), 3.0);
+        let degrees = super::Vec3D::from_angles_deg(0.5f64.to_degrees(), 20.0, 3.0);
+
+        assert!((typed.x - degrees.x).abs() < 1e-12);
+        assert!((typed.y - degrees.y).abs() < 1e-12);
+        assert!((typed.z - degrees.z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn hadamard() {
+        let a = super::Vec3D::new(1.0, 2.0, 3.0);
+        let b = super::Vec3D::new(4.0, 5.0, 6.0);
+
+        let product = a * b;
+        assert_eq!((product.x, product.y, product.z), (4.0, 10.0, 18.0));
+
+        let named = a.hadamard(&b);
+        assert_eq!((named.x, named.y, named.z), (4.0, 10.0, 18.0));
+    }
+
+    #[test]
+    fn checked_conversions() {
+        let zero = super::Vec3D::new(0.0, 0.0, 0.0);
+        assert_eq!(
+            zero.try_to_sphere().err(),
+            Some(super::VectorError::ZeroLength)
+        );
+        assert_eq!(
+            zero.try_normalize().err(),
+            Some(super::VectorError::ZeroLength)
+        );
+
+        let v = super::Vec3D::new(1.0, 2.0, 2.0);
+        let sphere = v.try_to_sphere().expect("Non-zero vector should convert");
+        assert_eq!(sphere.radius, 3.0);
+
+        let unit = v.try_normalize().expect("Non-zero vector should normalize");
+        assert!((unit.length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn approx_zero() {
+        let below = super::Vec3D::new(super::ZERO_EPSILON / 2.0, 0.0, 0.0);
+        let above = super::Vec3D::new(super::ZERO_EPSILON * 2.0, 0.0, 0.0);
+
+        assert!(below.approx_zero(super::ZERO_EPSILON));
+        assert!(!above.approx_zero(super::ZERO_EPSILON));
+
+        let n = below.normalize();
+        assert_eq!(
+            (n.x, n.y, n.z),
+            (0.0, 0.0, 0.0),
+            "Tiny vector should normalize to zero"
+        );
+        assert_eq!(below.to_sphere().azimuth, 0.0);
+        assert_eq!(below.angle_between(&above), 0.0);
+
+        let n = above.normalize();
+        assert_eq!(
+            (n.x, n.y, n.z),
+            (1.0, 0.0, 0.0),
+            "Vector above the threshold keeps its direction"
+        );
+        assert!(above.try_normalize().is_ok());
+    }
+
+    #[test]
+    fn angle_between() {
+        let a = super::Vec3D::new(1.0, 0.0, 0.0);
+
+        assert_eq!(a.angle_between(&super::Vec3D::new(2.0, 0.0, 0.0)), 0.0);
+        assert_eq!(
+            a.angle_between(&super::Vec3D::new(-1.0, 0.0, 0.0)),
+            std::f64::consts::PI
+        );
+        assert!(
+            (a.angle_between(&super::Vec3D::new(1.0, 1.0, 0.0)) - 45f64.to_radians()).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn angle_to_horizontal() {
+        let up = super::Vec3D::new(1.0, 0.0, 1.0);
+        assert!((up.angle_to_horizontal() - 45f64.to_radians()).abs() < 1e-12);
+
+        let level = super::Vec3D::new(3.0, 4.0, 0.0);
+        assert_eq!(level.angle_to_horizontal(), 0.0);
+
+        let down = super::Vec3D::new(0.0, 1.0, -1.0);
+        assert!((down.angle_to_horizontal() + 45f64.to_radians()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn angle_to_axis() {
+        let v = super::Vec3D::new(1.0, 0.0, 1.0);
+        let z_axis = super::Vec3D::new(0.0, 0.0, 5.0);
+
+        assert!((v.angle_to_axis(&z_axis) - 45f64.to_radians()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn componentwise_powers() {
+        let v = super::Vec3D::new(4.0, 9.0, 16.0).sqrt();
+        assert_eq!((v.x, v.y, v.z), (2.0, 3.0, 4.0));
+
+        let v = super::Vec3D::new(2.0, -3.0, 0.5).powi(3);
+        assert_eq!((v.x, v.y, v.z), (8.0, -27.0, 0.125));
+
+        let v = super::Vec3D::new(4.0, 9.0, 16.0).powf(0.5);
+        assert_eq!((v.x, v.y, v.z), (2.0, 3.0, 4.0));
+
+        assert!(super::Vec3D::new(-1.0, 0.0, 0.0).sqrt().x.is_nan());
+    }
+
+    #[test]
+    fn same_direction() {
+        let a = super::Vec3D::new(1.0, 0.0, 0.0);
+
+        assert!(a.same_direction(&super::Vec3D::new(5.0, 0.0, 0.0), 1e-9));
+        assert!(!a.same_direction(&super::Vec3D::new(-1.0, 0.0, 0.0), 1e-9));
+        assert!(!a.same_direction(&super::Vec3D::new(1.0, 0.1, 0.0), 1e-3));
+        assert!(a.same_direction(&super::Vec3D::new(1.0, 0.1, 0.0), 0.1));
+
+        let zero = super::Vec3D::new(0.0, 0.0, 0.0);
+        assert!(
+            !zero.same_direction(&zero, 1.0),
+            "Zero vectors have no direction"
+        );
+        assert!(
+            !a.same_direction(&zero, 1.0),
+            "Zero vectors have no direction"
+        );
+    }
+
+    #[test]
+    fn const_constructors() {
+        const UP: super::Vec3D = super::Vec3D::unit_z();
+        const ORIGIN: super::Vec3D = super::Vec3D::zero();
+        const CUSTOM: super::Vec3D = super::Vec3D::new(1.0, 2.0, 3.0);
+
+        assert_eq!((UP.x, UP.y, UP.z), (0.0, 0.0, 1.0));
+        assert_eq!((ORIGIN.x, ORIGIN.y, ORIGIN.z), (0.0, 0.0, 0.0));
+        assert_eq!((CUSTOM.x, CUSTOM.y, CUSTOM.z), (1.0, 2.0, 3.0));
+
+        let x = super::Vec3D::unit_x();
+        let y = super::Vec3D::unit_y();
+        assert_eq!((x.x, x.y, x.z), (1.0, 0.0, 0.0));
+        assert_eq!((y.x, y.y, y.z), (0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn ricochet() {
+        let v = super::Vec3D::new(3.0, -1.0, -2.0);
+        let normal = super::Vec3D::new(0.0, 1.0, 1.0).normalize();
+
+        let reflected = v.reflect(&normal);
+        let bounced = v.ricochet(&normal, 1.0, 0.0);
+        assert!(
+            (reflected - bounced).length() < 1e-12,
+            "Should reduce to reflect"
+        );
+
+        let stuck = v.ricochet(&normal, 0.5, 1.0);
+        let tangential = stuck - normal * stuck.dot(&normal);
+        assert!(
+            tangential.length() < 1e-12,
+            "Full friction should remove the tangential part"
+        );
+        assert!((stuck.dot(&normal) + 0.5 * v.dot(&normal)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sphere_round_trip() {
+        for v in [
+            super::Vec3D::new(1.0, 0.0, 0.0),
+            super::Vec3D::new(-3.0, 4.0, 2.0),
+            super::Vec3D::new(-1.0, -2.0, -5.0),
+            super::Vec3D::new(0.0, 0.0, 7.0),
+        ] {
+            let back = v.to_sphere().to_vec();
+            assert!(
+                (back - v).length() < 1e-12,
+                "{v:?} round tripped to {back:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate_spherical() {
+        let a = super::Vec3D::new(3.0, 0.0, 0.0);
+        let b = super::Vec3D::new(0.0, 3.0, 0.0);
+        let mid = a.interpolate_spherical(&b, 0.5);
+
+        assert!((mid.length() - 3.0).abs() < 1e-12);
+        assert!(
+            (mid.x - mid.y).abs() < 1e-12,
+            "Direction should be 45 degrees"
+        );
+        assert!(mid.z.abs() < 1e-12);
+
+        let start = a.interpolate_spherical(&b, 0.0);
+        let end = a.interpolate_spherical(&b, 1.0);
+        assert!((start - a).length() < 1e-12);
+        assert!((end - b).length() < 1e-12);
+    }
+
+    #[test]
+    fn interpolate_spherical_shortest_arc() {
+        let a = super::Vec3D::from_angles_deg(170.0, 0.0, 1.0);
+        let b = super::Vec3D::from_angles_deg(-170.0, 0.0, 1.0);
+        let mid = a.interpolate_spherical(&b, 0.5);
+
+        assert!(
+            (mid.x + 1.0).abs() < 1e-12,
+            "Should pass through 180 degrees"
+        );
+        assert!(mid.y.abs() < 1e-12);
+    }
+
+    #[test]
+    fn clamp_to_cone() {
+        let axis = super::Vec3D::new(0.0, 0.0, 2.0);
+        let limit = 30f64.to_radians();
+
+        let inside = super::Vec3D::new(0.1, 0.0, 1.0);
+        let unchanged = inside.clamp_to_cone(&axis, limit);
+        assert_eq!(
+            (unchanged.x, unchanged.y, unchanged.z),
+            (inside.x, inside.y, inside.z)
+        );
+
+        let outside = super::Vec3D::new(3.0, 4.0, 1.0);
+        let clamped = outside.clamp_to_cone(&axis, limit);
+        assert!((clamped.angle_between(&axis) - limit).abs() < 1e-12);
+        assert!((clamped.length() - outside.length()).abs() < 1e-12);
+        assert!(
+            (clamped.y / clamped.x - 4.0 / 3.0).abs() < 1e-12,
+            "Azimuth should be kept"
+        );
+
+        let behind = super::Vec3D::new(0.0, 0.0, -1.0);
+        let clamped = behind.clamp_to_cone(&axis, limit);
+        assert!((clamped.angle_between(&axis) - limit).abs() < 1e-12);
+    }
+
+    #[test]
+    fn round_to() {
+        let v = super::Vec3D::new(1.2345, 2.0, 3.0).round_to(2);
+        assert_eq!((v.x, v.y, v.z), (1.23, 2.0, 3.0));
+
+        let v = super::Vec3D::new(-0.0049, 0.125, 9.99999).round_to(3);
+        assert_eq!((v.x, v.y, v.z), (-0.005, 0.125, 10.0));
+
+        let v = super::Vec3D::new(1.5, -1.5, 0.4).round();
+        assert_eq!((v.x, v.y, v.z), (2.0, -2.0, 0.0));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let row = [1.0, 2.0, 3.0];
+        let v = super::Vec3D::try_from(&row[..]).expect("Three components should convert");
+        assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+
+        let short = super::Vec3D::try_from(&row[..2]).unwrap_err();
+        assert_eq!(
+            short,
+            super::VectorError::LengthMismatch {
+                expected: 3,
+                actual: 2
+            }
+        );
+        assert_eq!(short.to_string(), "expected 3 components, got 2");
+
+        let long = [0.0; 4];
+        assert!(super::Vec3D::try_from(&long[..]).is_err());
+    }
+
+    #[test]
+    fn move_towards() {
+        let start = super::Vec3D::new(1.0, 1.0, 1.0);
+        let target = super::Vec3D::new(4.0, 5.0, 1.0);
+
+        let near = start.move_towards(&target, 5.5);
+        assert_eq!(
+            (near.x, near.y, near.z),
+            (4.0, 5.0, 1.0),
+            "Should snap to the target"
+        );
+
+        let far = start.move_towards(&target, 2.5);
+        assert!(((far - start).length() - 2.5).abs() < 1e-12);
+        assert!((target - far).same_direction(&(target - start), 1e-9));
+
+        let arrived = target.move_towards(&target, 1.0);
+        assert_eq!((arrived.x, arrived.y, arrived.z), (4.0, 5.0, 1.0));
+    }
+
+    #[test]
+    fn clamp_axis() {
+        let below_ground = super::Vec3D::new(12.0, -3.0, -0.25);
+        let v = below_ground.clamp_z(0.0, 100.0);
+        assert_eq!((v.x, v.y, v.z), (12.0, -3.0, 0.0));
+
+        let v = below_ground.clamp_x(-5.0, 5.0).clamp_y(-1.0, 1.0);
+        assert_eq!((v.x, v.y, v.z), (5.0, -1.0, -0.25));
+    }
+
+    #[test]
+    fn alternative_lengths() {
+        let v = super::Vec3D::new(3.0, -4.0, 0.0);
+        assert_eq!(v.manhattan_length(), 7.0);
+        assert_eq!(v.chebyshev_length(), 4.0);
+
+        let v = super::Vec3D::new(-1.0, 2.0, -6.0);
+        assert_eq!(v.manhattan_length(), 9.0);
+        assert_eq!(v.chebyshev_length(), 6.0);
+    }
+
+    #[test]
+    fn great_circle_distance() {
+        let a = super::Vec3D::new(0.0, 2.0, 0.0);
+        let b = super::Vec3D::new(0.0, 0.0, 5.0);
+
+        assert_eq!(a.great_circle_distance(&b), std::f64::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn slerp_unclamped() {
+        let a = super::Vec3D::unit_x();
+        let b = super::Vec3D::from_angles_deg(60.0, 0.0, 1.0);
+
+        let mid = a.slerp_unclamped(&b, 0.5);
+        assert!((mid.length() - 1.0).abs() < 1e-12);
+        assert!((mid.angle_between(&a) - 30f64.to_radians()).abs() < 1e-12);
+
+        let past = a.slerp_unclamped(&b, 2.0);
+        assert!((past.length() - 1.0).abs() < 1e-12);
+        assert!((past.angle_between(&a) - 120f64.to_radians()).abs() < 1e-12);
+        assert!(
+            (past.angle_between(&b) - 60f64.to_radians()).abs() < 1e-12,
+            "Should overshoot b"
+        );
+    }
+
+    #[test]
+    fn signum() {
+        let v = super::Vec3D::new(-2.0, 0.0, 3.0).signum();
+        assert_eq!((v.x, v.y, v.z), (-1.0, 0.0, 1.0));
+
+        let v = super::Vec3D::new(-0.0, f64::NAN, f64::NEG_INFINITY).signum();
+        assert_eq!(v.x, 0.0);
+        assert!(v.y.is_nan());
+        assert_eq!(v.z, -1.0);
+    }
+
+    #[test]
+    fn copysign() {
+        let speed = super::Vec3D::new(2.0, 2.0, 2.0);
+        let direction = super::Vec3D::new(-5.0, 0.5, -0.0);
+        let v = speed.copysign(&direction);
+
+        assert_eq!((v.x, v.y, v.z), (-2.0, 2.0, -2.0));
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let (x, y, z): (f64, f64, f64) = super::Vec3D::new(1.0, 2.0, 3.0).into();
+        assert_eq!((x, y, z), (1.0, 2.0, 3.0));
+
+        let v = super::Vec3D::from((4.0, 5.0, 6.0));
+        assert_eq!(v, super::Vec3D::new(4.0, 5.0, 6.0));
+    }
+
+    #[test]
+    fn rotation_to() {
+        let a = super::Vec3D::new(2.0, 0.0, 0.0);
+
+        let (axis, angle) = a.rotation_to(&super::Vec3D::new(0.0, 0.0, 3.0));
+        assert_eq!(axis, super::Vec3D::new(0.0, -1.0, 0.0));
+        assert!((angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+        let (axis, angle) = a.rotation_to(&super::Vec3D::new(5.0, 0.0, 0.0));
+        assert_eq!(angle, 0.0);
+        assert!((axis.length() - 1.0).abs() < 1e-12);
+
+        let (axis, angle) = a.rotation_to(&super::Vec3D::new(-1.0, 0.0, 0.0));
+        assert_eq!(angle, std::f64::consts::PI);
+        assert!((axis.length() - 1.0).abs() < 1e-12);
+        assert!(axis.dot(&a).abs() < 1e-12, "Axis should be perpendicular");
+    }
+
+    #[test]
+    fn is_unit() {
+        let v = super::Vec3D::new(1.0, -2.0, 2.0);
+        assert_eq!(v.length_squared(), 9.0);
+        assert!(!v.is_unit(1e-9));
+        assert!(v.normalize().is_unit(1e-9));
+
+        assert!(!super::Vec3D::new(2.0, 0.0, 0.0).is_unit(1e-9));
+        assert!(super::Vec3D::new(1.05, 0.0, 0.0).is_unit(0.1));
+        assert!(!super::Vec3D::zero().is_unit(1e-9));
+    }
+
+    #[test]
+    fn exact_and_bit_eq() {
+        let positive = super::Vec3D::new(0.0, 1.0, 2.0);
+        let negative = super::Vec3D::new(-0.0, 1.0, 2.0);
+        assert!(
+            positive.exact_eq(&negative),
+            "IEEE equality ignores the sign of zero"
+        );
+        assert!(
+            !positive.bit_eq(&negative),
+            "Bit equality keeps the sign of zero"
+        );
+
+        let nan = super::Vec3D::new(f64::NAN, 1.0, 2.0);
+        assert!(
+            !nan.exact_eq(&nan),
+            "NaN never equals itself under IEEE rules"
+        );
+        assert!(nan.bit_eq(&nan), "Identical NaN bits are equal");
+        assert_eq!(nan.exact_eq(&nan), nan == nan, "Should match PartialEq");
+    }
+
+    #[test]
+    fn enu_ned() {
+        let (east, north, up) = (4.0, -7.0, 2.5);
+        let ned = super::Vec3D::new(east, north, up).enu_to_ned();
+        assert_eq!(ned, super::Vec3D::new(north, east, -up));
+
+        let v = super::Vec3D::new(1.5, 2.5, -3.5);
+        assert_eq!(v.enu_to_ned().ned_to_enu(), v);
+        assert_eq!(v.ned_to_enu().enu_to_ned(), v);
+    }
+
+    #[test]
+    fn reflect_about_plane() {
+        let p = super::Vec3D::new(0.0, 0.0, 5.0);
+        let mirrored =
+            p.reflect_about_plane(&super::Vec3D::new(0.0, 0.0, 1.0), &super::Vec3D::unit_z());
+        assert_eq!(mirrored, super::Vec3D::new(0.0, 0.0, -3.0));
+
+        let point = super::Vec3D::new(3.0, 1.0, 0.0);
+        let normal = super::Vec3D::new(1.0, 1.0, 0.0);
+        let mirrored = point.reflect_about_plane(&super::Vec3D::new(1.0, 1.0, 7.0), &normal);
+        assert!((mirrored - super::Vec3D::new(1.0, -1.0, 0.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn rotate_around_line() {
+        let pivot = super::Vec3D::new(1.0, 0.0, 0.0);
+        let axis = super::Vec3D::new(0.0, 0.0, 3.0);
+        let p = super::Vec3D::new(4.0, 2.0, 5.0);
+
+        let distance_to_line = |v: super::Vec3D| (v - pivot).length_xy();
+        let start = distance_to_line(p);
+
+        for degrees in [30.0, 90.0, 180.0, 270.0] {
+            let rotated = p.rotate_around_line(&pivot, &axis, f64::to_radians(degrees));
+            assert!((distance_to_line(rotated) - start).abs() < 1e-12);
+            assert!(
+                (rotated.z - p.z).abs() < 1e-12,
+                "Height along the axis should be kept"
+            );
+        }
+
+        let half_turn = p.rotate_around_line(&pivot, &axis, std::f64::consts::PI);
+        assert!((half_turn - super::Vec3D::new(-2.0, -2.0, 5.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn reflect_safe() {
+        let v = super::Vec3D::new(3.0, -2.0, -1.0);
+        let raw_normal = super::Vec3D::new(1.0, 2.0, 2.0);
+
+        let expected = v.reflect(&raw_normal.normalize());
+        assert!((v.reflect_safe(&raw_normal) - expected).length() < 1e-12);
+
+        assert_eq!(v.reflect_safe(&super::Vec3D::zero()), v);
+    }
+
+    #[test]
+    fn clamp_each() {
+        let limits = super::Vec3D::new(3.0, 3.0, 3.0);
+        let v = super::Vec3D::new(5.0, -5.0, 2.0).clamp_each(&limits);
+        assert_eq!(v, super::Vec3D::new(3.0, -3.0, 2.0));
+
+        let v = super::Vec3D::new(-1.0, 1.0, 10.0).clamp_each(&super::Vec3D::new(0.5, 2.0, 0.0));
+        assert_eq!(v, super::Vec3D::new(-0.5, 1.0, 0.0));
+    }
+
+    #[test]
+    fn hermite() {
+        let p0 = super::Vec3D::new(1.0, 2.0, 3.0);
+        let v0 = super::Vec3D::new(4.0, -1.0, 2.0);
+        let p1 = super::Vec3D::new(-2.0, 5.0, 0.0);
+        let v1 = super::Vec3D::new(0.0, 3.0, -6.0);
+
+        assert_eq!(super::Vec3D::hermite(&p0, &v0, &p1, &v1, 0.0), p0);
+        assert_eq!(super::Vec3D::hermite(&p0, &v0, &p1, &v1, 1.0), p1);
+
+        let h = 1e-6;
+        let tangent = (super::Vec3D::hermite(&p0, &v0, &p1, &v1, h) - p0) * (1.0 / h);
+        assert!((tangent - v0).length() < 1e-4);
+    }
+
+    #[test]
+    fn project_onto_plane_normalized() {
+        let v = super::Vec3D::new(2.0, -1.0, 3.0);
+        let normal = super::Vec3D::new(0.0, 1.0, 1.0);
+
+        let unit = normal.normalize();
+        let manual = v - unit * v.dot(&unit);
+        let expected = manual * (v.length() / manual.length());
+
+        let projected = v.project_onto_plane_normalized(&normal);
+        assert!((projected - expected).length() < 1e-12);
+        assert!((projected.length() - v.length()).abs() < 1e-12);
+        assert!(projected.dot(&normal).abs() < 1e-12);
+
+        let perpendicular = super::Vec3D::new(0.0, 2.0, 2.0);
+        assert_eq!(
+            perpendicular.project_onto_plane_normalized(&normal),
+            super::Vec3D::zero()
+        );
+    }
+
+    #[test]
+    fn scalar_triple() {
+        let x = super::Vec3D::unit_x();
+        let y = super::Vec3D::unit_y();
+        let z = super::Vec3D::unit_z();
+        assert_eq!(super::Vec3D::scalar_triple(&x, &y, &z), 1.0);
+        assert_eq!(super::Vec3D::scalar_triple(&y, &x, &z), -1.0);
+
+        let a = super::Vec3D::new(1.0, 2.0, 0.5);
+        let b = super::Vec3D::new(-3.0, 1.0, 2.0);
+        let c = a * 2.0 + b * -0.5;
+        assert!(super::Vec3D::scalar_triple(&a, &b, &c).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vector_triple() {
+        let a = super::Vec3D::new(1.0, 2.0, 0.5);
+        let b = super::Vec3D::new(-3.0, 1.0, 2.0);
+        let c = super::Vec3D::new(0.5, -1.0, 4.0);
+
+        // BAC-CAB rule
+        let expected = b * a.dot(&c) - c * a.dot(&b);
+        assert!((super::Vec3D::vector_triple(&a, &b, &c) - expected).length() < 1e-12);
+    }
+
+    #[test]
+    fn weighted_average() {
+        let a = super::Vec3D::new(1.0, 2.0, 3.0);
+        let b = super::Vec3D::new(-3.0, 0.0, 6.0);
+        let c = super::Vec3D::new(5.0, 1.0, 0.0);
+
+        let avg = super::Vec3D::weighted_average(&[(a, 2.0), (b, 2.0), (c, 2.0)]).unwrap();
+        let centroid = (a + b + c) * (1.0 / 3.0);
+        assert!((avg - centroid).length() < 1e-12);
+
+        for weight in [0.1, 1.0, 42.0, -3.0] {
+            let single = super::Vec3D::weighted_average(&[(a, weight)]).unwrap();
+            assert!((single - a).length() < 1e-12);
+        }
+
+        assert_eq!(super::Vec3D::weighted_average(&[(a, 1.0), (b, -1.0)]), None);
+    }
+
+    #[test]
+    fn approach_max_length() {
+        let mut v = super::Vec3D::new(30.0, -40.0, 10.0);
+        let direction = v.normalize();
+
+        for _ in 0..200 {
+            let next = v.approach_max_length(10.0, 0.1);
+            assert!(next.length() <= v.length());
+            assert!(next.length() >= 10.0);
+            v = next;
+        }
+
+        assert!((v.length() - 10.0).abs() < 1e-6);
+        assert!(v.same_direction(&direction, 1e-9));
+
+        let under = super::Vec3D::new(1.0, 2.0, 2.0);
+        assert_eq!(under.approach_max_length(10.0, 0.5), under);
+        assert_eq!(under.approach_max_length(3.0, 0.5), under);
+    }
+
+    #[test]
+    fn update_length_negative() {
+        let original = super::Vec3D::new(1.0, -2.0, 2.0);
+        let mut v = original;
+        v.update_length(-6.0);
+
+        assert!((v.length() - 6.0).abs() < 1e-12);
+        assert!(v.same_direction(&original, 1e-9));
+        assert!((v - original * 2.0).length() < 1e-12);
+    }
+
+    #[test]
+    fn update_length_zero_vector() {
+        let mut v = super::Vec3D::zero();
+        v.update_length(5.0);
+
+        assert_eq!(v, super::Vec3D::zero());
+    }
+
+    #[test]
+    fn clamp_each_negative_limits() {
+        let v = super::Vec3D::new(5.0, -5.0, 0.5).clamp_each(&super::Vec3D::new(-1.0, 1.0, -3.0));
+        assert_eq!(v, super::Vec3D::new(1.0, -1.0, 0.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_each_nan_limit() {
+        super::Vec3D::new(1.0, 1.0, 1.0).clamp_each(&super::Vec3D::new(1.0, f64::NAN, 1.0));
+    }
+
+    #[test]
+    fn slerp_unclamped_opposite() {
+        let a = super::Vec3D::unit_x() * 2.0;
+        let b = a * -1.0;
+
+        let mid = a.slerp_unclamped(&b, 0.5);
+        assert!((mid.length() - 2.0).abs() < 1e-12);
+        assert!(mid.dot(&a).abs() < 1e-12);
+
+        assert!((a.slerp_unclamped(&b, 0.0) - a).length() < 1e-12);
+        assert!((a.slerp_unclamped(&b, 1.0) - b).length() < 1e-12);
+        assert!((a.slerp_unclamped(&b, 0.25).length() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotation_to_zero_vector() {
+        let zero = super::Vec3D::zero();
+
+        assert_eq!(super::Vec3D::unit_x().rotation_to(&zero), (zero, 0.0));
+        assert_eq!(zero.rotation_to(&super::Vec3D::unit_x()), (zero, 0.0));
+        assert_eq!(zero.rotation_to(&zero), (zero, 0.0));
+    }
+
+    #[test]
+    fn rotate_around_line_zero_axis() {
+        let p = super::Vec3D::new(2.0, 3.0, -1.0);
+        let point = super::Vec3D::unit_x();
+
+        for angle in [0.5, std::f64::consts::FRAC_PI_2, 3.0] {
+            assert_eq!(
+                p.rotate_around_line(&point, &super::Vec3D::zero(), angle),
+                p
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod vec_2d {
+    #[test]
+    fn from_angle_deg() {
+        let v = super::Vec2D::from_angle_deg(90.0, 1.0);
+        assert!(v.x.abs() < 1e-12, "x should be approximately 0");
+        assert!((v.y - 1.0).abs() < 1e-12, "y should be approximately 1");
+
+        let v = super::Vec2D::from_angle_deg(180.0, 2.0);
+        assert!((v.x + 2.0).abs() < 1e-12, "x should be approximately -2");
+        assert!(v.y.abs() < 1e-12, "y should be approximately 0");
+    }
+
+    #[test]
+    fn into_iter() {
+        let v = super::Vec2D::new(1.0, 2.0);
+
+        let by_ref: Vec<f64> = (&v).into_iter().collect();
+        assert_eq!(by_ref, vec![1.0, 2.0]);
+
+        let mut components = Vec::new();
+        for c in v {
+            components.push(c);
+        }
+        assert_eq!(components, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn with_components() {
+        let v = super::Vec2D::new(1.0, 2.0);
+        let moved = v.with_x(3.0).with_y(4.0);
+
+        assert_eq!((moved.x, moved.y), (3.0, 4.0));
+        assert_eq!((v.x, v.y), (1.0, 2.0), "Original should be unchanged");
+    }
+
+    #[test]
+    fn perp() {
+        let v = super::Vec2D::new(1.0, 0.0).perp();
+        assert_eq!((v.x, v.y), (0.0, 1.0));
+
+        let v = super::Vec2D::new(3.0, -2.0);
+        let left = v.perp();
+        let right = v.perp_cw();
+
+        assert_eq!(
+            v.x * left.x + v.y * left.y,
+            0.0,
+            "perp should be perpendicular"
+        );
+        assert_eq!(
+            v.x * right.x + v.y * right.y,
+            0.0,
+            "perp_cw should be perpendicular"
+        );
+        assert_eq!((left.x, left.y), (-right.x, -right.y));
+    }
+
+    #[test]
+    fn perp_dot() {
+        let a = super::Vec2D::new(2.0, 1.0);
+
+        let ccw = super::Vec2D::new(1.0, 3.0);
+        assert!(
+            a.perp_dot(&ccw) > 0.0,
+            "Counter-clockwise turn should be positive"
+        );
+
+        let cw = super::Vec2D::new(3.0, -1.0);
+        assert!(a.perp_dot(&cw) < 0.0, "Clockwise turn should be negative");
+
+        let collinear = super::Vec2D::new(-4.0, -2.0);
+        assert_eq!(
+            a.perp_dot(&collinear),
+            0.0,
+            "Collinear vectors should be zero"
+        );
+    }
+
+    #[test]
+    fn const_constructors() {
+        const ORIGIN: super::Vec2D = super::Vec2D::zero();
+        const RIGHT: super::Vec2D = super::Vec2D::unit_x();
+        const UP: super::Vec2D = super::Vec2D::unit_y();
+
+        assert_eq!((ORIGIN.x, ORIGIN.y), (0.0, 0.0));
+        assert_eq!((RIGHT.x, RIGHT.y), (1.0, 0.0));
+        assert_eq!((UP.x, UP.y), (0.0, 1.0));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let row = [1.0, 2.0, 3.0];
+        let v = super::Vec2D::try_from(&row[..2]).expect("Two components should convert");
+        assert_eq!((v.x, v.y), (1.0, 2.0));
+
+        assert_eq!(
+            super::Vec2D::try_from(&row[..]).unwrap_err(),
+            super::VectorError::LengthMismatch {
+                expected: 2,
+                actual: 3
+            }
+        );
+        assert!(super::Vec2D::try_from(&row[..0]).is_err());
+    }
+
+    #[test]
+    fn alternative_lengths() {
+        let v = super::Vec2D::new(3.0, -4.0);
+        assert_eq!(v.manhattan_length(), 7.0);
+        assert_eq!(v.chebyshev_length(), 4.0);
+    }
+
+    #[test]
+    fn signum_and_copysign() {
+        let v = super::Vec2D::new(4.0, -0.0).signum();
+        assert_eq!((v.x, v.y), (1.0, 0.0));
+
+        let v = super::Vec2D::new(-3.0, 3.0).copysign(&super::Vec2D::new(1.0, -1.0));
+        assert_eq!((v.x, v.y), (3.0, -3.0));
+    }
+
+    #[test]
+    fn tuple_conversions() {
+        let (x, y): (f64, f64) = super::Vec2D::new(1.0, 2.0).into();
+        assert_eq!((x, y), (1.0, 2.0));
+
+        let v: super::Vec2D = (3.0, 4.0).into();
+        assert_eq!(v, super::Vec2D::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn to_3d() {
+        let v = super::Vec2D::new(100.0, 5.0).to_3d(0.0);
+        assert_eq!(v, super::Vec3D::new(100.0, 0.0, 5.0));
+
+        let v = super::Vec2D::new(10.0, -1.0).to_3d(90f64.to_radians());
+        assert!(v.x.abs() < 1e-12);
+        assert!((v.y - 10.0).abs() < 1e-12);
+        assert_eq!(v.z, -1.0);
+        assert!((v.length_xy() - 10.0).abs() < 1e-12, "Range should be kept");
+    }
+
+    #[test]
+    fn is_unit() {
+        assert!(super::Vec2D::unit_y().is_unit(1e-12));
+        assert!(!super::Vec2D::new(2.0, 0.0).is_unit(1e-12));
+        assert_eq!(super::Vec2D::new(3.0, 4.0).length_squared(), 25.0);
+    }
+
+    #[test]
+    fn ops() {
+        let v = super::Vec2D::new(1.0, 1.0) + (2.0, 3.0);
+        assert_eq!(v, super::Vec2D::new(3.0, 4.0));
+
+        let v = super::Vec2D::new(1.0, 1.0) + super::Vec2D::new(2.0, 3.0);
+        assert_eq!(v, super::Vec2D::new(3.0, 4.0));
+        assert_eq!(v - super::Vec2D::new(1.0, 1.0), super::Vec2D::new(2.0, 3.0));
+        assert_eq!(v * 2.0, super::Vec2D::new(6.0, 8.0));
+
+        let mut pos = super::Vec2D::zero();
+        pos += (0.5, -1.0);
+        pos += super::Vec2D::new(0.5, 2.0);
+        pos *= 3.0;
+        assert_eq!(pos, super::Vec2D::new(3.0, 3.0));
+    }
+
+    #[test]
+    fn cross_z() {
+        let z = super::Vec2D::new(1.0, 0.0).cross_z(&super::Vec2D::new(0.0, 1.0));
+        assert_eq!(z, super::Vec3D::new(0.0, 0.0, 1.0));
+
+        let a = super::Vec2D::new(2.0, 1.0);
+        let b = super::Vec2D::new(-1.0, 3.0);
+        let as_3d = super::Vec3D::new(a.x, a.y, 0.0).cross(&super::Vec3D::new(b.x, b.y, 0.0));
+        assert_eq!(a.cross_z(&b), as_3d);
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(super::Vec2D::new(-3.0, 4.0).length(), 5.0);
+        assert_eq!(super::Vec2D::zero().length(), 0.0);
+    }
+
+    #[test]
+    fn update_length() {
+        let mut v = super::Vec2D::new(-3.0, 4.0);
+        v.update_length(-10.0);
+        assert!((v.x + 6.0).abs() < 1e-12);
+        assert!((v.y - 8.0).abs() < 1e-12);
+
+        let mut zero = super::Vec2D::zero();
+        zero.update_length(5.0);
+        assert_eq!(zero, super::Vec2D::zero());
+    }
+
+    #[test]
+    fn sphere_round_trip() {
+        for v in [
+            super::Vec2D::new(1.0, 0.0),
+            super::Vec2D::new(-3.0, 4.0),
+            super::Vec2D::new(-1.0, -2.0),
+        ] {
+            let back = v.to_sphere().to_vec();
+            assert!((back.x - v.x).abs() < 1e-12 && (back.y - v.y).abs() < 1e-12);
+        }
+
+        let zero = super::Vec2D::zero().to_sphere();
+        assert_eq!((zero.polar, zero.radius), (0.0, 0.0));
+    }
+}
+
+#[cfg(test)]
+mod vec_3d_sphere {
+    use std::f64::consts::TAU;
+
+    fn sphere() -> super::Vec3DSphere {
+        super::Vec3DSphere {
+            radius: 4.0,
+            azimuth: 1.0,
+            polar: 0.7,
+        }
+    }
+
+    #[test]
+    fn rotate_azimuth_full_turn() {
+        let v = sphere();
+        let rotated = v.rotate_azimuth(TAU);
+
+        assert!((rotated.azimuth - v.azimuth).abs() < 1e-12);
+        assert!((rotated.to_vec() - v.to_vec()).length() < 1e-12);
+    }
+
+    #[test]
+    fn scale_radius_keeps_direction() {
+        let v = sphere();
+        let scaled = v.scale_radius(2.5).to_vec();
+        let original = v.to_vec();
+
+        assert!((scaled.length() - 2.5 * original.length()).abs() < 1e-12);
+        assert!(scaled.same_direction(&original, 1e-9));
+    }
+
+    #[test]
+    fn tilt_polar_past_pole() {
+        let v = sphere();
+        let tilted = v.tilt_polar(-1.0);
+
+        assert!((0.0..=std::f64::consts::PI).contains(&tilted.polar));
+        assert!((tilted.polar - 0.3).abs() < 1e-12);
+
+        let expected = super::Vec3DSphere { polar: -0.3, ..v }.to_vec();
+        assert!((tilted.to_vec() - expected).length() < 1e-12);
+    }
+
+    #[test]
+    fn display_degenerate() {
+        let v = super::Vec3DSphere {
+            radius: 0.0,
+            azimuth: f64::NAN,
+            polar: f64::NAN,
+        };
+        let text = v.to_string();
+
+        assert!(!text.contains("NaN"));
+        assert_eq!(text, "(radius: 0, azimuth: 0, polar: 0)");
+        assert_eq!(
+            super::Vec3D::zero().to_sphere().to_string(),
+            "(radius: 0, azimuth: 0, polar: 0)"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let v = super::Vec3DSphere {
+            radius: 2.0,
+            azimuth: 0.5,
+            polar: 1.5,
+        };
+
+        assert_eq!(v.to_string(), "(radius: 2, azimuth: 0.5, polar: 1.5)");
+    }
+}
+
+#[cfg(test)]
+mod vec_2d_sphere {
+    use std::f64::consts::{PI, TAU};
+
+    fn close(a: super::Vec2D, b: super::Vec2D) -> bool {
+        (a.x - b.x).abs() < 1e-12 && (a.y - b.y).abs() < 1e-12
+    }
+
+    #[test]
+    fn normalize_negative_radius() {
+        let mut v = super::Vec2DSphere {
+            polar: -PI / 2.0,
+            radius: -1.0,
+        };
+        let before = v.to_vec();
+        v.normalize();
+
+        assert_eq!(v.radius, 1.0);
+        assert!((0.0..TAU).contains(&v.polar));
+        assert!(close(v.to_vec(), before));
+    }
+
+    #[test]
+    fn normalize_wraps_polar() {
+        for polar in [-7.0, -1e-20, 0.0, 3.0, TAU, 20.0] {
+            let mut v = super::Vec2DSphere { polar, radius: 2.0 };
+            let before = v.to_vec();
+            v.normalize();
+
+            assert!((0.0..TAU).contains(&v.polar), "polar {polar}");
+            assert!(close(v.to_vec(), before), "polar {polar}");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "approx"))]
+mod approx_eq {
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_relative_ne};
+
+    #[test]
+    fn vec_3d() {
+        let a = super::Vec3D::new(0.1 + 0.2, 1.0, -3.0);
+        let b = super::Vec3D::new(0.3, 1.0, -3.0);
+
+        assert_relative_eq!(a, b);
+        assert_abs_diff_eq!(a, super::Vec3D::new(0.3001, 1.0, -3.0), epsilon = 1e-3);
+        assert_relative_ne!(a, super::Vec3D::new(0.31, 1.0, -3.0));
+    }
+
+    #[test]
+    fn vec_2d() {
+        let a = super::Vec2D::from_angle_deg(90.0, 1.0);
+
+        assert_relative_eq!(a, super::Vec2D::new(0.0, 1.0), epsilon = 1e-12);
+        assert_relative_ne!(a, super::Vec2D::new(0.0, 1.1));
     }
 }
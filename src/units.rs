@@ -0,0 +1,175 @@
+/// An angle with an explicit unit
+///
+/// Stores the angle internally in radians. Construct it with
+/// [`Angle::degrees`] or [`Angle::radians`] so the unit is always visible at
+/// the call site.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::units::Angle;
+/// let a = Angle::degrees(180.0);
+///
+/// assert_eq!(a.as_radians(), std::f64::consts::PI);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Angle {
+    radians: f64,
+}
+
+#[allow(dead_code)]
+impl Angle {
+    /// Creates a new angle from degrees
+    pub fn degrees(degrees: f64) -> Angle {
+        Angle {
+            radians: degrees.to_radians(),
+        }
+    }
+
+    /// Creates a new angle from radians
+    pub fn radians(radians: f64) -> Angle {
+        Angle { radians }
+    }
+
+    /// Returns the angle in radians
+    pub fn as_radians(&self) -> f64 {
+        self.radians
+    }
+
+    /// Returns the angle in degrees
+    pub fn as_degrees(&self) -> f64 {
+        self.radians.to_degrees()
+    }
+}
+
+/// Meters per second in one foot per second
+const MPS_PER_FPS: f64 = 0.3048;
+
+/// A speed with an explicit unit
+///
+/// Stores the speed internally in meters per second. Construct it with
+/// [`Velocity::mps`] or [`Velocity::fps`] so the unit is always visible at
+/// the call site.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::units::Velocity;
+/// let v = Velocity::fps(1000.0);
+///
+/// assert_eq!(v.as_mps(), 304.8);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Velocity {
+    mps: f64,
+}
+
+#[allow(dead_code)]
+impl Velocity {
+    /// Creates a new velocity from meters per second
+    pub fn mps(mps: f64) -> Velocity {
+        Velocity { mps }
+    }
+
+    /// Creates a new velocity from feet per second
+    pub fn fps(fps: f64) -> Velocity {
+        Velocity {
+            mps: fps * MPS_PER_FPS,
+        }
+    }
+
+    /// Returns the velocity in meters per second
+    pub fn as_mps(&self) -> f64 {
+        self.mps
+    }
+
+    /// Returns the velocity in feet per second
+    pub fn as_fps(&self) -> f64 {
+        self.mps / MPS_PER_FPS
+    }
+}
+
+/// Kilograms per square meter in one pound per square inch
+const KG_M2_PER_LB_IN2: f64 = 0.453_592_37 / (0.0254 * 0.0254);
+
+/// Converts a ballistic coefficient from lb/in² to kg/m²
+///
+/// Published BC values are usually given in lb/in², while the rest of the
+/// crate works in SI units.
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::units::bc_imperial_to_si;
+///
+/// assert!((bc_imperial_to_si(1.0) - 703.0696).abs() < 1e-4);
+/// ```
+pub fn bc_imperial_to_si(bc: f64) -> f64 {
+    bc * KG_M2_PER_LB_IN2
+}
+
+/// Converts a ballistic coefficient from kg/m² to lb/in²
+///
+/// # Examples
+/// ```rust
+/// use ballistics_calculator::units::bc_si_to_imperial;
+///
+/// assert!((bc_si_to_imperial(703.0696) - 1.0).abs() < 1e-6);
+/// ```
+pub fn bc_si_to_imperial(bc: f64) -> f64 {
+    bc / KG_M2_PER_LB_IN2
+}
+
+#[cfg(test)]
+mod angle {
+    use super::Angle;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn degrees_to_radians() {
+        let a = Angle::degrees(180.0);
+        assert!((a.as_radians() - PI).abs() < 1e-12);
+        assert!((a.as_degrees() - 180.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn radians_to_degrees() {
+        let a = Angle::radians(PI / 2.0);
+        assert!((a.as_degrees() - 90.0).abs() < 1e-12);
+        assert_eq!(a.as_radians(), PI / 2.0);
+    }
+}
+
+#[cfg(test)]
+mod velocity {
+    use super::Velocity;
+
+    #[test]
+    fn fps_to_mps() {
+        let v = Velocity::fps(1000.0);
+        assert!((v.as_mps() - 304.8).abs() < 1e-9);
+        assert!((v.as_fps() - 1000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn mps_to_fps() {
+        let v = Velocity::mps(304.8);
+        assert!((v.as_fps() - 1000.0).abs() < 1e-9);
+        assert_eq!(v.as_mps(), 304.8);
+    }
+}
+
+#[cfg(test)]
+mod ballistic_coefficient {
+    use super::{bc_imperial_to_si, bc_si_to_imperial};
+
+    #[test]
+    fn imperial_to_si() {
+        // G1 BC of a typical .308 match bullet
+        assert!((bc_imperial_to_si(0.475) - 333.958).abs() < 1e-3);
+    }
+
+    #[test]
+    fn round_trip() {
+        for bc in [0.1, 0.243, 0.475, 1.0, 2.5] {
+            assert!((bc_si_to_imperial(bc_imperial_to_si(bc)) - bc).abs() < 1e-12);
+        }
+    }
+}